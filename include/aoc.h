@@ -0,0 +1,50 @@
+/* Generated from src/ffi.rs, do not edit. Regenerate with
+ * UPDATE_SNAPSHOTS=1 cargo test --features c-api --test ffi */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The answer was written to out_buf */
+#define AOC_OK 0
+
+/* A pointer was NULL or out_len was 0 */
+#define AOC_INVALID_ARGUMENT 1
+
+/* There is no solution for that year, day or part */
+#define AOC_NO_SUCH_DAY 2
+
+/* The answer and its NUL do not fit in out_len bytes */
+#define AOC_BUFFER_TOO_SMALL 3
+
+/* The solver gave up on the input, out_buf holds why */
+#define AOC_PANICKED 4
+
+/* Arithmetic overflowed (checked builds), out_buf holds where */
+#define AOC_OVERFLOWED 5
+
+/* The part has no solution yet */
+#define AOC_UNSOLVED 6
+
+/* Solves one part of a day of 2023, writing the answer to out_buf as a
+ * NUL-terminated string. input_ptr holds the puzzle input as it was downloaded.
+ * Parameters are the defaults, aoc.toml is not read. Returns one of the AOC_
+ * statuses above. Safe to call from several threads at once. */
+int aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t input_len,
+              char *out_buf, size_t out_len);
+
+/* aoc_solve for days of any year */
+int aoc_solve_year(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input_ptr,
+                   size_t input_len, char *out_buf, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
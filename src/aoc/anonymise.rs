@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+use advent_of_code::config::Config;
+use advent_of_code::input;
+use advent_of_code::rng::Rng;
+use advent_of_code::solution::catch_panic;
+
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut input_path: Option<String> = None;
+    let mut out_path: Option<String> = None;
+    let mut seed: Option<u64> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => input_path = iter.next().cloned(),
+            "--out" => out_path = iter.next().cloned(),
+            "--seed" => seed = Some(iter.next().and_then(|s| s.parse().ok())
+                .ok_or("anonymise: --seed needs a number")?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, rest) = crate::parse_day("anonymise", &positional)?;
+    if let Some(extra) = rest.first() {
+        return Err(format!("anonymise: unexpected argument '{}'", extra));
+    }
+
+    let input = match &input_path {
+        Some(path) => input::load(path).map(Cow::Owned).map_err(|e| format!("cannot read {}: {}", path, e))?,
+        None => day.default_input()?,
+    };
+
+    // Print the seed so that a rewrite can be reproduced
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0)
+    });
+    eprintln!("seed {}", seed);
+
+    let mut rng = Rng::new(seed);
+    let anonymised = catch_panic(|| (day.anonymise)(&input, &mut rng))
+        .map_err(|e| format!("anonymise: cannot rewrite the input: {}", e))?
+        .ok_or(format!("anonymise: day {} has no anonymiser", day.day))?;
+
+    match &out_path {
+        Some(path) => fs::write(path, &anonymised).map_err(|e| format!("cannot write {}: {}", path, e))?,
+        None => io::stdout().write_all(&anonymised).map_err(|e| e.to_string())?,
+    }
+
+    let path = out_path.or(input_path).unwrap_or_else(|| day.input_path());
+    match catch_panic(|| (day.parse)(&anonymised, &config.params(day.year, day.day, &path))) {
+        Ok(()) => {
+            eprintln!("anonymised input parses");
+            Ok(())
+        }
+        Err(e) => Err(format!("anonymised input does not parse: {}", e)),
+    }
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use advent_of_code::config::Config;
+use advent_of_code::{find_day, input, DEFAULT_YEAR};
+use advent_of_code::solution::{catch_panic, millis, Answer, RunOptions};
+
+
+enum Outcome {
+    Solved(String),
+    Failed(String),
+    Panicked(String),
+    TimedOut(String),
+    Overflowed(String),
+}
+
+// -------------------------------------------------------------------------- //
+
+/// Number in a directory name such as `day5` or `2022`
+fn numbered_dir<T: std::str::FromStr>(entry: &fs::DirEntry, prefix: &str) -> Option<T> {
+    let name = entry.file_name();
+    let number = name.to_str()?.strip_prefix(prefix)?;
+    if !entry.path().is_dir() || number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<fs::DirEntry>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("cannot read {}: {}", dir.display(), e))?;
+    Ok(entries.flatten().collect())
+}
+
+/// Finds `dayN/*.txt` files below `dir`, and `YYYY/dayN/*.txt` for other years than
+/// the default one, ordered by year, day and then file name
+fn find_inputs(dir: &Path) -> Result<Vec<(u16, u8, PathBuf)>, String> {
+    let mut year_dirs = vec![(DEFAULT_YEAR, dir.to_path_buf())];
+    for entry in read_dir(dir)? {
+        if let Some(year) = numbered_dir::<u16>(&entry, "").filter(|y| *y >= 1000) {
+            year_dirs.push((year, entry.path()));
+        }
+    }
+
+    let mut inputs = Vec::new();
+    for (year, year_dir) in year_dirs {
+        for entry in read_dir(&year_dir)? {
+            let Some(day) = numbered_dir::<u8>(&entry, "day") else {
+                continue;
+            };
+            for file in read_dir(&entry.path())? {
+                let path = file.path();
+                if path.is_file() && path.extension().is_some_and(|e| e == "txt") {
+                    inputs.push((year, day, path));
+                }
+            }
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn solve(year: u16, day_num: u8, path: &Path, config: &Config, options: RunOptions) -> Outcome {
+    let day = match find_day(year, day_num) {
+        Some(d) => d,
+        None => return Outcome::Failed(format!("no solution for {} day {}", year, day_num)),
+    };
+    let input = match input::load(path) {
+        Ok(i) => i,
+        Err(e) => return Outcome::Failed(format!("cannot read input: {}", e)),
+    };
+
+    let params = config.params(year, day_num, &path.to_string_lossy());
+    match catch_panic(|| (day.run)(&input, &params, options)) {
+        Ok(run) if matches!(run.part1, Answer::TimedOut(_)) || matches!(run.part2, Answer::TimedOut(_)) => {
+            Outcome::TimedOut(format!("part 1: {}, part 2: {}", run.part1, run.part2))
+        }
+        Ok(run) if matches!(run.part1, Answer::Overflow(_)) || matches!(run.part2, Answer::Overflow(_)) => {
+            Outcome::Overflowed(format!("part 1: {}, part 2: {}", run.part1, run.part2))
+        }
+        Ok(run) => Outcome::Solved(format!("part 1: {}, part 2: {} ({:.3} ms)",
+            run.part1, run.part2, millis(run.timings.total()))),
+        Err(msg) => Outcome::Panicked(msg),
+    }
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut dir: Option<&Path> = None;
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ if dir.is_none() => dir = Some(Path::new(arg)),
+            _ => return Err(format!("batch: unexpected argument '{}'", arg)),
+        }
+    }
+    let dir = dir.ok_or("batch: missing <dir>")?;
+
+    let inputs = find_inputs(dir)?;
+    if inputs.is_empty() {
+        return Err(format!("batch: no dayN/*.txt or YYYY/dayN/*.txt inputs found in {}", dir.display()));
+    }
+
+    let mut failures = 0;
+    for (year, day, path) in &inputs {
+        let name = path.strip_prefix(dir).unwrap_or(path).display();
+
+        match solve(*year, *day, path, config, options) {
+            Outcome::Solved(s) => println!("{:<32} {}", name, s),
+            Outcome::Failed(e) => {
+                failures += 1;
+                println!("{:<32} FAILED: {}", name, e);
+            }
+            Outcome::Panicked(e) => {
+                failures += 1;
+                println!("{:<32} PANICKED: {}", name, e);
+            }
+            Outcome::TimedOut(e) => {
+                failures += 1;
+                println!("{:<32} TIMED OUT: {}", name, e);
+            }
+            Outcome::Overflowed(e) => {
+                failures += 1;
+                println!("{:<32} OVERFLOWED: {}", name, e);
+            }
+        }
+    }
+
+    println!("{} inputs, {} solved, {} failed", inputs.len(), inputs.len() - failures, failures);
+    if failures > 0 {
+        Err(format!("{} inputs failed", failures))
+    } else {
+        Ok(())
+    }
+}
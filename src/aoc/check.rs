@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+use advent_of_code::config::Config;
+use advent_of_code::input;
+use advent_of_code::solution::catch_panic;
+
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (day, input_path) = match crate::parse_day("check", &args)? {
+        (day, []) => (day, None),
+        (day, [path]) => (day, Some(*path)),
+        (_, [_, extra, ..]) => return Err(format!("check: unexpected argument '{}'", extra)),
+    };
+
+    let input = match input_path {
+        Some(path) => input::load(path).map(Cow::Owned).map_err(|e| format!("cannot read {}: {}", path, e))?,
+        None => day.default_input()?,
+    };
+    let path = input_path.map(String::from).unwrap_or_else(|| day.input_path());
+    let params = config.params(day.year, day.day, &path);
+
+    let assumptions = (day.assumptions)();
+    if assumptions.is_empty() {
+        println!("{} day {} has no recorded assumptions", day.year, day.day);
+        return Ok(());
+    }
+
+    let mut failures = 0;
+    let mut unsafe_strategies: Vec<(u8, &str)> = Vec::new();
+    for a in &assumptions {
+        // A check that panics is as good as a failed one
+        match catch_panic(|| (a.check)(&input, &params)).and_then(|r| r) {
+            Ok(()) => println!("  ok    {}", a.name),
+            Err(e) => {
+                println!("  FAIL  {}: {}", a.name, e);
+                failures += 1;
+                unsafe_strategies.extend(a.relied_on_by);
+            }
+        }
+    }
+
+    if failures == 0 {
+        println!("all assumptions hold for {}", path);
+        return Ok(());
+    }
+
+    unsafe_strategies.sort();
+    unsafe_strategies.dedup();
+    let names: Vec<String> = unsafe_strategies.iter()
+        .map(|(part, name)| format!("part {} {}", part, name)).collect();
+    println!("unsafe on {}: {}", path, names.join(", "));
+
+    Err(format!("{} of {} assumptions do not hold", failures, assumptions.len()))
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+use std::time::Duration;
+use advent_of_code::config::Config;
+use advent_of_code::input;
+use advent_of_code::solution::{millis, Answer, RunOptions, StrategyRun};
+
+
+fn print_part(part: u8, runs: &[&StrategyRun]) -> bool {
+    let fastest = runs.iter().map(|r| r.time).min().unwrap_or(Duration::ZERO);
+    let finished: Vec<&Answer> = runs.iter().map(|r| &r.answer)
+        .filter(|a| !matches!(a, Answer::TimedOut(_))).collect();
+    let agree = finished.windows(2).all(|w| w[0] == w[1]);
+
+    println!("part {}:{}", part, if agree { "" } else { " DISAGREEMENT" });
+    for r in runs {
+        let relative = r.time.as_secs_f64() / fastest.as_secs_f64().max(f64::EPSILON);
+        println!("  {:<16} {:>20} {:>12.3} ms {:>10.2}x",
+            r.name, r.answer.to_string(), millis(r.time), relative);
+    }
+
+    agree
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut input_path: Option<String> = None;
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => input_path = iter.next().cloned(),
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, rest) = crate::parse_day("diff", &positional)?;
+    if let Some(extra) = rest.first() {
+        return Err(format!("diff: unexpected argument '{}'", extra));
+    }
+
+    let input = match &input_path {
+        Some(path) => input::load(path).map(Cow::Owned).map_err(|e| format!("cannot read {}: {}", path, e))?,
+        None => day.default_input()?,
+    };
+    let path = input_path.unwrap_or_else(|| day.input_path());
+
+    let runs = (day.run_strategies)(&input, &config.params(day.year, day.day, &path), options);
+
+    let mut all_agree = true;
+    for part in [1, 2] {
+        let part_runs: Vec<&StrategyRun> = runs.iter().filter(|r| r.part == part).collect();
+        all_agree &= print_part(part, &part_runs);
+    }
+
+    if all_agree {
+        Ok(())
+    } else {
+        Err(format!("strategies disagree on {} day {}", day.year, day.day))
+    }
+}
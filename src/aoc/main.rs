@@ -0,0 +1,98 @@
+use std::env;
+use std::process;
+use std::time::Duration;
+use advent_of_code::args;
+use advent_of_code::config::Config;
+use advent_of_code::solution::Day;
+use advent_of_code::{find_day, DEFAULT_YEAR};
+
+mod anonymise;
+mod batch;
+mod check;
+mod diff;
+mod new;
+mod perf;
+mod report;
+mod run;
+mod scale;
+mod serve;
+mod shrink;
+
+
+fn usage() -> ! {
+    eprintln!("usage: aoc <command> [options]");
+    eprintln!();
+    eprintln!("commands:");
+    eprintln!("  run [<year>] <day> [--part <p>]    solve one day, the year defaults to {}", DEFAULT_YEAR);
+    eprintln!("      [--input <file>] [--dump-model json]");
+    eprintln!("  report --out <file.html|file.md>   run every day and write a report");
+    eprintln!("  diff <day> [--input <file>]        compare all strategies of a day");
+    eprintln!("  batch <dir>                        solve every <dir>/[YYYY/]dayN/*.txt input");
+    eprintln!("  serve [--port <n>]                 answer POST [/<year>]/day/<n>/part/<p> over HTTP");
+    eprintln!("  bench [--runs <n>] [--day <n>]     time every part and record it in the history");
+    eprintln!("        [--year <y>]                 only bench one year");
+    eprintln!("        [--strategies]               also time the alternative strategies");
+    eprintln!("  perf-history [--threshold <pct>]   show timing trends and flag regressions");
+    eprintln!("  new [<year>] <day>                 scaffold and register a new day");
+    eprintln!("  anonymise <day> [--out <file>]     rewrite an input into a shareable equivalent");
+    eprintln!("  check <day> [<input>]              test the input properties the solvers assume");
+    eprintln!("  scale <day> [--from <n>]           fit solve times on growing generated inputs");
+    eprintln!("        [--factor <f>] [--steps <n>]");
+    eprintln!("  shrink <day> <input>               cut an input down to the lines that still fail");
+    eprintln!("         --predicate <panic|disagree> [--out <file>]");
+    eprintln!();
+    eprintln!("diff, anonymise, check, scale and shrink also take an optional <year> before <day>");
+    eprintln!("run, report, diff, batch, serve, bench, scale and shrink also accept --timeout <seconds> per part");
+    process::exit(2);
+}
+
+fn parse_timeout(value: Option<&String>) -> Result<Duration, String> {
+    args::parse_timeout(value.map_or("", String::as_str))
+}
+
+/// Picks the day out of `[<year>] <day> ...` arguments, returning the ones after it.
+/// A leading four digit number is taken as the year, which otherwise defaults.
+fn parse_day<'a>(command: &str, args: &'a [&'a str]) -> Result<(&'static Day, &'a [&'a str]), String> {
+    let (year, rest) = match args {
+        [year, day, rest @ ..] if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) => {
+            (year.parse().unwrap(), (day, rest))
+        }
+        [day, rest @ ..] => (DEFAULT_YEAR, (day, rest)),
+        [] => return Err(format!("{}: missing <day>", command)),
+    };
+    let (day, rest) = rest;
+
+    let day_num: u8 = day.parse().map_err(|_| format!("{}: <day> must be a number", command))?;
+    let day = find_day(year, day_num).ok_or(format!("{}: no solution for {} day {}", command, year, day_num))?;
+    Ok((day, rest))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let config = Config::load().unwrap_or_else(|e| {
+        eprintln!("aoc: {}", e);
+        process::exit(1);
+    });
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::main(&args[1..], &config),
+        Some("report") => report::main(&args[1..], &config),
+        Some("diff") => diff::main(&args[1..], &config),
+        Some("batch") => batch::main(&args[1..], &config),
+        Some("serve") => serve::main(&args[1..], &config),
+        Some("bench") => perf::bench(&args[1..], &config),
+        Some("perf-history") => perf::history(&args[1..], &config),
+        Some("new") => new::main(&args[1..], &config),
+        Some("anonymise") => anonymise::main(&args[1..], &config),
+        Some("check") => check::main(&args[1..], &config),
+        Some("scale") => scale::main(&args[1..], &config),
+        Some("shrink") => shrink::main(&args[1..], &config),
+        _ => usage(),
+    };
+
+    if let Err(e) = result {
+        eprintln!("aoc: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::Path;
+use advent_of_code::config::Config;
+use advent_of_code::{find_day, DEFAULT_YEAR};
+
+
+const MOD_TEMPLATE: &str = "\
+use crate::cancel::Cancel;
+use crate::config::Params;
+use crate::solution::{Answer, Solution};
+
+
+pub struct Day{day};
+
+// -------------------------------------------------------------------------- //
+
+impl Solution for Day{day} {
+    type Model = ();
+
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    #[cfg(feature = \"embed-inputs\")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!(\"input.txt\"));
+
+    fn parse(_bytes: &[u8], _params: &Params) {}
+
+    fn part1(_model: &(), _cancel: &Cancel) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_model: &(), _cancel: &Cancel) -> Answer {
+        Answer::Unsolved
+    }
+}
+";
+
+const MAIN_TEMPLATE: &str = "\
+use advent_of_code::year{year}::day{day}::Day{day};
+use advent_of_code::solution;
+
+fn main() {
+    solution::main::<Day{day}>();
+}
+";
+
+const TEST_TEMPLATE: &str = "\
+use advent_of_code::config::Params;
+use advent_of_code::year{year}::day{day}::Day{day};
+use advent_of_code::solution::{run, Answer, RunOptions};
+
+
+// Paste the example from the puzzle text here and fill in its expected answers
+const EXAMPLE: &str = \"\\
+\";
+
+#[test]
+fn example() {
+    let run = run::<Day{day}>(EXAMPLE.as_bytes(), &Params::default(), RunOptions::default());
+    assert_eq!(run.part1, Answer::Unsolved);
+    assert_eq!(run.part2, Answer::Unsolved);
+}
+";
+
+const YEAR_TEMPLATE: &str = "\
+pub mod day{day};
+
+use crate::solution::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day{day}::Day{day}>(),
+];
+";
+
+const BIN_TEMPLATE: &str = "\
+[[bin]]
+name = \"{name}\"
+path = \"src/year{year}/day{day}/main.rs\"
+
+";
+
+// -------------------------------------------------------------------------- //
+
+/// Inserts `text` after the last line starting with `prefix`
+fn insert_after_last(source: &str, prefix: &str, text: &str) -> Option<String> {
+    let line_start = source.rmatch_indices(prefix)
+        .map(|(i, _)| i)
+        .find(|&i| i == 0 || source.as_bytes()[i - 1] == b'\n')?;
+    let line_end = source[line_start..].find('\n').map_or(source.len(), |n| line_start + n + 1);
+
+    Some(format!("{}{}{}", &source[..line_end], text, &source[line_end..]))
+}
+
+/// Binary and test name of a day; the default year keeps the short `dayN` form
+fn target_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{}", day)
+    } else {
+        format!("day{}-{}", day, year)
+    }
+}
+
+/// Adds a day to the existing `src/yearYYYY/mod.rs`
+fn register_day(year_mod: &str, day: u8) -> Option<String> {
+    let year_mod = insert_after_last(year_mod, "pub mod day", &format!("pub mod day{};\n", day))?;
+
+    let table = year_mod.find("pub const DAYS")?;
+    let end = table + year_mod[table..].find("];")?;
+    Some(format!("{}    Day::of::<day{day}::Day{day}>(),\n{}", &year_mod[..end], &year_mod[end..], day = day))
+}
+
+/// Adds a new year module to `src/lib.rs`, keeping `YEARS` oldest first
+fn register_year(lib: &str, year: u16) -> Option<String> {
+    let lib = insert_after_last(lib, "pub mod year", &format!("pub mod year{};\n", year))?;
+
+    let table = lib.find("pub const YEARS")?;
+    let end = table + lib[table..].find("];")?;
+    let at = lib[table..end].match_indices("\n    (")
+        .map(|(i, _)| table + i + 1)
+        .find(|&i| lib[i + 5..].get(..4).and_then(|y| y.parse::<u16>().ok()).is_some_and(|y| y > year))
+        .unwrap_or(end);
+    Some(format!("{}    ({year}, year{year}::DAYS),\n{}", &lib[..at], &lib[at..], year = year))
+}
+
+fn register_bin(manifest: &str, year: u16, day: u8) -> String {
+    let block = BIN_TEMPLATE
+        .replace("{name}", &target_name(year, day))
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+
+    // Keep the day binaries together, ahead of the runner
+    match manifest.find("[[bin]]\nname = \"aoc\"") {
+        Some(i) => format!("{}{}{}", &manifest[..i], block, &manifest[i..]),
+        None => format!("{}\n{}", manifest.trim_end(), block.trim_end()) + "\n",
+    }
+}
+
+fn write_new(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path, e))?;
+    println!("created {}", path);
+    Ok(())
+}
+
+fn read_source(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {} (run from the repository root)", path, e))
+}
+
+pub fn main(args: &[String], _config: &Config) -> Result<(), String> {
+    let (year, day) = match args {
+        [year, day] => (year.as_str(), day),
+        [day] => ("", day),
+        [] => return Err(String::from("new: missing <day>")),
+        [_, _, extra, ..] => return Err(format!("new: unexpected argument '{}'", extra)),
+    };
+    let year: u16 = match year {
+        "" => DEFAULT_YEAR,
+        y => y.parse().ok().filter(|y| (2015..=9999).contains(y))
+            .ok_or("new: <year> must be a year from 2015 on")?,
+    };
+    let day: u8 = day.parse().ok().filter(|d| (1..=25).contains(d))
+        .ok_or("new: <day> must be a number from 1 to 25")?;
+
+    let year_dir = format!("src/year{}", year);
+    let dir = format!("{}/day{}", year_dir, day);
+    let test = format!("tests/{}.rs", target_name(year, day));
+    if find_day(year, day).is_some() || Path::new(&dir).exists() || Path::new(&test).exists() {
+        return Err(format!("new: {} day {} already exists", year, day));
+    }
+
+    let expand = |template: &str| template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+
+    let year_mod_path = format!("{}/mod.rs", year_dir);
+    let (year_mod, lib) = if Path::new(&year_mod_path).exists() {
+        let year_mod = register_day(&read_source(&year_mod_path)?, day)
+            .ok_or(format!("new: cannot find the day modules and DAYS in {}", year_mod_path))?;
+        (year_mod, None)
+    } else {
+        let lib = register_year(&read_source("src/lib.rs")?, year)
+            .ok_or("new: cannot find the year modules and YEARS in src/lib.rs")?;
+        (expand(YEAR_TEMPLATE), Some(lib))
+    };
+    let manifest = register_bin(&read_source("Cargo.toml")?, year, day);
+
+    fs::create_dir_all(&dir).map_err(|e| format!("cannot create {}: {}", dir, e))?;
+    write_new(&format!("{}/mod.rs", dir), &expand(MOD_TEMPLATE))?;
+    write_new(&format!("{}/main.rs", dir), &expand(MAIN_TEMPLATE))?;
+    write_new(&format!("{}/input.txt", dir), "")?;
+    fs::create_dir_all("tests").map_err(|e| format!("cannot create tests: {}", e))?;
+    write_new(&test, &expand(TEST_TEMPLATE))?;
+
+    fs::write(&year_mod_path, year_mod).map_err(|e| format!("cannot write {}: {}", year_mod_path, e))?;
+    println!("registered {} day {} in {}", year, day, year_mod_path);
+    if let Some(lib) = lib {
+        fs::write("src/lib.rs", lib).map_err(|e| format!("cannot write src/lib.rs: {}", e))?;
+        println!("registered {} in src/lib.rs", year);
+    }
+    fs::write("Cargo.toml", manifest).map_err(|e| format!("cannot write Cargo.toml: {}", e))?;
+    println!("added the {} binary to Cargo.toml", target_name(year, day));
+
+    Ok(())
+}
@@ -0,0 +1,282 @@
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use advent_of_code::config::Config;
+use advent_of_code::{days, find_day, DEFAULT_YEAR};
+use advent_of_code::solution::{millis, Answer, Day, RunOptions};
+
+
+const HISTORY_FILE: &str = "perf-history.tsv";
+const HISTORY_HEADER: &str = "# timestamp_ms\tcommit\tyear\tday\tpart\tstrategy\tms";
+
+/// One timed solve, as stored in the history file
+#[derive(Debug, Clone)]
+struct Sample {
+    timestamp: u64,
+    commit: String,
+    year: u16,
+    day: u8,
+    part: u8,
+    strategy: String,
+    ms: f64,
+}
+
+/// Median time of one day, part and strategy for each recorded `aoc bench` run
+struct Series {
+    year: u16,
+    day: u8,
+    part: u8,
+    strategy: String,
+    medians: Vec<(String, f64)>,
+}
+
+// -------------------------------------------------------------------------- //
+
+/// Commit hash of the checked out HEAD, as git sees it from the working directory, which
+/// may be any directory of the repository or of one of its worktrees
+fn git_head() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok().map(|hash| hash.trim().to_string())
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+fn parse_sample(line: &str) -> Option<Sample> {
+    match line.split('\t').collect::<Vec<_>>()[..] {
+        [timestamp, commit, year, day, part, strategy, ms] => Some(Sample {
+            timestamp: timestamp.parse().ok()?,
+            commit: commit.to_string(),
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            strategy: strategy.to_string(),
+            ms: ms.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+fn read_history(path: &str) -> Result<Vec<Sample>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("cannot read {}: {}", path, e)),
+    };
+
+    text.lines().enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| parse_sample(line).ok_or(format!("{}: line {}: malformed sample", path, i + 1)))
+        .collect()
+}
+
+fn append_history(path: &str, samples: &[Sample]) -> Result<(), String> {
+    let is_new = fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true);
+
+    let mut out = String::new();
+    if is_new {
+        out += HISTORY_HEADER;
+        out.push('\n');
+    }
+    for s in samples {
+        writeln!(out, "{}\t{}\t{}\t{}\t{}\t{}\t{:.6}",
+            s.timestamp, s.commit, s.year, s.day, s.part, s.strategy, s.ms).unwrap();
+    }
+
+    OpenOptions::new().create(true).append(true).open(path)
+        .and_then(|mut file| file.write_all(out.as_bytes()))
+        .map_err(|e| format!("cannot write {}: {}", path, e))
+}
+
+/// Times every part of `day` `runs` times, skipping solves that timed out
+fn bench_day(day: &Day, config: &Config, runs: usize, strategies: bool, options: RunOptions)
+    -> Result<Vec<(u8, &'static str, Duration)>, String>
+{
+    let input = day.default_input()?;
+    let params = config.params(day.year, day.day, &day.input_path());
+    let mut timings = Vec::new();
+
+    for _ in 0..runs {
+        if strategies {
+            for r in (day.run_strategies)(&input, &params, options) {
+                if !matches!(r.answer, Answer::TimedOut(_)) {
+                    timings.push((r.part, r.name, r.time));
+                }
+            }
+        } else {
+            let run = (day.run)(&input, &params, options);
+            for (part, answer, time) in [(1, &run.part1, run.timings.part1), (2, &run.part2, run.timings.part2)] {
+                if !matches!(answer, Answer::TimedOut(_)) {
+                    timings.push((part, "default", time));
+                }
+            }
+        }
+    }
+
+    Ok(timings)
+}
+
+// -------------------------------------------------------------------------- //
+
+pub fn bench(args: &[String], config: &Config) -> Result<(), String> {
+    let mut runs = 5usize;
+    let mut only_year: Option<u16> = None;
+    let mut only_day: Option<u8> = None;
+    let mut strategies = false;
+    let mut history = String::from(HISTORY_FILE);
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" => runs = iter.next().and_then(|r| r.parse().ok()).filter(|&r| r > 0)
+                .ok_or("bench: --runs needs a positive number")?,
+            "--year" => only_year = Some(iter.next().and_then(|y| y.parse().ok())
+                .ok_or("bench: --year needs a number")?),
+            "--day" => only_day = Some(iter.next().and_then(|d| d.parse().ok())
+                .ok_or("bench: --day needs a number")?),
+            "--strategies" => strategies = true,
+            "--history" => history = iter.next().cloned().ok_or("bench: --history needs a file")?,
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => return Err(format!("bench: unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days: Vec<&Day> = match (only_year, only_day) {
+        (year, Some(d)) => {
+            let year = year.unwrap_or(DEFAULT_YEAR);
+            vec![find_day(year, d).ok_or(format!("bench: no solution for {} day {}", year, d))?]
+        }
+        (Some(year), None) => days().filter(|day| day.year == year).collect(),
+        (None, None) => days().collect(),
+    };
+    if days.is_empty() {
+        return Err(String::from("bench: no solutions for that year"));
+    }
+
+    let commit = git_head().unwrap_or_else(|| String::from("unknown"));
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0);
+    let mut samples = Vec::new();
+
+    for day in days {
+        eprintln!("benchmarking {} day {}", day.year, day.day);
+        let timings = bench_day(day, config, runs, strategies, options)?;
+
+        let mut keys: Vec<(u8, &str)> = timings.iter().map(|&(p, s, _)| (p, s)).collect();
+        keys.sort();
+        keys.dedup();
+        for (part, strategy) in keys {
+            let mut ms: Vec<f64> = timings.iter()
+                .filter(|t| t.0 == part && t.1 == strategy)
+                .map(|t| millis(t.2)).collect();
+            println!("{} day {:>2} part {} {:<16} median {:>12.3} ms over {} runs",
+                day.year, day.day, part, strategy, median(&mut ms), ms.len());
+
+            samples.extend(ms.into_iter().map(|ms| Sample {
+                timestamp,
+                commit: commit.clone(),
+                year: day.year,
+                day: day.day,
+                part,
+                strategy: strategy.to_string(),
+                ms,
+            }));
+        }
+    }
+
+    append_history(&history, &samples)?;
+    println!("appended {} samples to {}", samples.len(), history);
+    Ok(())
+}
+
+pub fn history(args: &[String], _config: &Config) -> Result<(), String> {
+    let mut threshold = 10.0;
+    let mut last = 5usize;
+    let mut history = String::from(HISTORY_FILE);
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--threshold" => threshold = iter.next().and_then(|t| t.parse().ok())
+                .filter(|t: &f64| t.is_finite() && *t >= 0.0)
+                .ok_or("perf-history: --threshold needs a percentage")?,
+            "--last" => last = iter.next().and_then(|l| l.parse().ok()).filter(|&l| l > 0)
+                .ok_or("perf-history: --last needs a positive number")?,
+            "--history" => history = iter.next().cloned().ok_or("perf-history: --history needs a file")?,
+            _ => return Err(format!("perf-history: unexpected argument '{}'", arg)),
+        }
+    }
+
+    let samples = read_history(&history)?;
+    if samples.is_empty() {
+        return Err(format!("perf-history: no samples in {}, run `aoc bench` first", history));
+    }
+
+    // Group by bench invocation, in the order they were recorded
+    let mut series: Vec<Series> = Vec::new();
+    let mut start = 0;
+    while start < samples.len() {
+        let run = (&samples[start].commit, samples[start].timestamp);
+        let end = samples[start..].iter()
+            .position(|s| (&s.commit, s.timestamp) != run)
+            .map_or(samples.len(), |n| start + n);
+
+        let mut keys: Vec<(u16, u8, u8, &str)> = samples[start..end].iter()
+            .map(|s| (s.year, s.day, s.part, s.strategy.as_str())).collect();
+        keys.sort();
+        keys.dedup();
+
+        for (year, day, part, strategy) in keys {
+            let mut ms: Vec<f64> = samples[start..end].iter()
+                .filter(|s| s.year == year && s.day == day && s.part == part && s.strategy == strategy)
+                .map(|s| s.ms).collect();
+            let entry = (run.0.chars().take(8).collect(), median(&mut ms));
+
+            match series.iter_mut().find(|s| s.year == year && s.day == day && s.part == part && s.strategy == strategy) {
+                Some(s) => s.medians.push(entry),
+                None => series.push(Series { year, day, part, strategy: strategy.to_string(), medians: vec![entry] }),
+            }
+        }
+        start = end;
+    }
+    series.sort_by(|a, b| (a.year, a.day, a.part, &a.strategy).cmp(&(b.year, b.day, b.part, &b.strategy)));
+
+    let mut regressions = 0;
+    for s in &series {
+        let shown = &s.medians[s.medians.len().saturating_sub(last)..];
+        let trend: Vec<String> = shown.iter().map(|(commit, ms)| format!("{:.3} ({})", ms, commit)).collect();
+
+        let change = match s.medians.as_slice() {
+            [.., (_, prev), (_, latest)] => Some((latest - prev) / prev.max(f64::EPSILON) * 100.0),
+            _ => None,
+        };
+        let flag = match change {
+            Some(pct) if pct > threshold => {
+                regressions += 1;
+                format!(" {:+.1}% REGRESSION", pct)
+            }
+            Some(pct) => format!(" {:+.1}%", pct),
+            None => String::new(),
+        };
+
+        println!("{} day {:>2} part {} {:<16} {} ms{}", s.year, s.day, s.part, s.strategy, trend.join(" -> "), flag);
+    }
+
+    if regressions > 0 {
+        Err(format!("{} parts regressed by more than {}%", regressions, threshold))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use advent_of_code::days;
+use advent_of_code::config::Config;
+use advent_of_code::solution::{catch_panic, millis, Run, RunOptions};
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    Html,
+}
+
+struct DayReport {
+    year: u16,
+    day: u8,
+    run: Result<Run, String>,
+}
+
+// -------------------------------------------------------------------------- //
+
+impl Format {
+    fn from_path(path: &str) -> Option<Format> {
+        let ext = Path::new(path).extension()?.to_str()?;
+        match ext.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Keeps a cell from breaking out of its Markdown table row
+fn markdown_escape(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+/// A code fence longer than any run of backticks in `content`, so it cannot close early
+fn markdown_fence(content: &str) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat(longest.max(2) + 1)
+}
+
+fn run_all(config: &Config, options: RunOptions) -> Vec<DayReport> {
+    days().map(|day| {
+        let path = day.input_path();
+        eprintln!("running {} day {}", day.year, day.day);
+
+        let run = day.default_input().and_then(|input| {
+            catch_panic(|| (day.run)(&input, &config.params(day.year, day.day, &path), options))
+                .map_err(|msg| format!("panicked: {}", msg))
+        });
+        DayReport { year: day.year, day: day.day, run }
+    }).collect()
+}
+
+// -------------------------------------------------------------------------- //
+
+fn write_markdown(reports: &[DayReport]) -> String {
+    let mut out = String::from("# Advent of Code report\n\n");
+
+    out += "| Year | Day | Part 1 | Part 2 | Parse (ms) | Part 1 (ms) | Part 2 (ms) | Total (ms) |\n";
+    out += "|-----:|----:|-------:|-------:|-----------:|------------:|------------:|-----------:|\n";
+
+    for r in reports {
+        match &r.run {
+            Ok(run) => {
+                let t = &run.timings;
+                writeln!(out, "| {} | {} | {} | {} | {:.3} | {:.3} | {:.3} | {:.3} |",
+                    r.year, r.day, markdown_escape(&run.part1.to_string()), markdown_escape(&run.part2.to_string()),
+                    millis(t.parse), millis(t.part1), millis(t.part2), millis(t.total())).unwrap();
+            }
+            Err(e) => {
+                writeln!(out, "| {} | {} | {} | | | | | |", r.year, r.day, markdown_escape(e)).unwrap();
+            }
+        }
+    }
+
+    for r in reports {
+        if let Ok(Run { render: Some(render), .. }) = &r.run {
+            let fence = markdown_fence(render);
+            write!(out, "\n## {} day {}\n\n{}text\n{}\n{}\n", r.year, r.day, fence, render.trim_end(), fence).unwrap();
+        }
+    }
+
+    out
+}
+
+fn write_html(reports: &[DayReport]) -> String {
+    let mut out = String::from(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+        "<title>Advent of Code report</title>\n<style>\n",
+        "body { font-family: sans-serif; margin: 2em; }\n",
+        "table { border-collapse: collapse; }\n",
+        "th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: right; }\n",
+        "td.error { color: #b00; text-align: left; }\n",
+        "pre { line-height: 1.0; font-size: 0.7em; }\n",
+        "</style>\n</head>\n<body>\n<h1>Advent of Code report</h1>\n",
+    ));
+
+    out += "<table>\n<tr><th>Year</th><th>Day</th><th>Part 1</th><th>Part 2</th><th>Parse (ms)</th>";
+    out += "<th>Part 1 (ms)</th><th>Part 2 (ms)</th><th>Total (ms)</th></tr>\n";
+
+    for r in reports {
+        match &r.run {
+            Ok(run) => {
+                let t = &run.timings;
+                writeln!(out, concat!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.3}</td>",
+                    "<td>{:.3}</td><td>{:.3}</td><td>{:.3}</td></tr>"),
+                    r.year, r.day, run.part1, run.part2, millis(t.parse),
+                    millis(t.part1), millis(t.part2), millis(t.total())).unwrap();
+            }
+            Err(e) => {
+                writeln!(out, "<tr><td>{}</td><td>{}</td><td class=\"error\" colspan=\"6\">{}</td></tr>",
+                    r.year, r.day, html_escape(e)).unwrap();
+            }
+        }
+    }
+    out += "</table>\n";
+
+    for r in reports {
+        if let Ok(Run { render: Some(render), .. }) = &r.run {
+            write!(out, "<h2>{} day {}</h2>\n<pre>{}</pre>\n", r.year, r.day, html_escape(render.trim_end())).unwrap();
+        }
+    }
+
+    out += "</body>\n</html>\n";
+    out
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut out_path: Option<&str> = None;
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--out" => out_path = iter.next().map(String::as_str),
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => return Err(format!("report: unexpected argument '{}'", arg)),
+        }
+    }
+
+    let out_path = out_path.ok_or("report: missing --out <file>")?;
+    let format = Format::from_path(out_path)
+        .ok_or("report: output file must end in .html or .md")?;
+
+    let reports = run_all(config, options);
+    let contents = match format {
+        Format::Markdown => write_markdown(&reports),
+        Format::Html => write_html(&reports),
+    };
+
+    fs::write(out_path, contents).map_err(|e| format!("cannot write {}: {}", out_path, e))?;
+    println!("wrote {}", out_path);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+use advent_of_code::config::Config;
+use advent_of_code::input;
+use advent_of_code::solution::{millis, print_run, RunOptions};
+
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut input_path: Option<String> = None;
+    let mut part: Option<u8> = None;
+    let mut dump_model = false;
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => input_path = iter.next().cloned(),
+            "--part" => part = Some(iter.next().and_then(|p| p.parse().ok()).filter(|p| matches!(p, 1 | 2))
+                .ok_or("run: --part must be 1 or 2")?),
+            "--dump-model" => match iter.next().map(String::as_str) {
+                Some("json") => dump_model = true,
+                _ => return Err(String::from("run: --dump-model only supports json")),
+            },
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, rest) = crate::parse_day("run", &positional)?;
+    if let Some(extra) = rest.first() {
+        return Err(format!("run: unexpected argument '{}'", extra));
+    }
+
+    let input = match &input_path {
+        Some(path) => input::load(path).map(Cow::Owned).map_err(|e| format!("cannot read {}: {}", path, e))?,
+        None => day.default_input()?,
+    };
+    let path = input_path.unwrap_or_else(|| day.input_path());
+    let params = config.params(day.year, day.day, &path);
+
+    if dump_model {
+        let json = (day.dump_model)(&input, &params)
+            .ok_or(format!("run: {} day {} has no JSON form of its model", day.year, day.day))?;
+        println!("{:#}", json);
+        return Ok(());
+    }
+
+    match part {
+        Some(part) => {
+            let run = (day.run_part)(&input, &params, part, options);
+            println!("Part {} result: {}", part, run.answer);
+            println!("Completed in {} ms", millis(run.parse + run.solve));
+        }
+        None => print_run(&(day.run)(&input, &params, options)),
+    }
+
+    Ok(())
+}
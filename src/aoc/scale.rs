@@ -0,0 +1,233 @@
+use std::time::Duration;
+use advent_of_code::config::Config;
+use advent_of_code::rng::Rng;
+use advent_of_code::solution::{catch_panic, millis, Answer, RunOptions};
+
+
+/// Fastest time of one part and strategy at every size it finished in time
+struct Series {
+    part: u8,
+    name: &'static str,
+    points: Vec<(usize, f64)>,
+    timed_out_at: Option<usize>,
+}
+
+/// How well one growth curve explains a series, as the RMS of the relative errors
+struct Fit {
+    curve: String,
+    error: f64,
+}
+
+/// A polynomial growth curve, as ln f(n) so that it can be scaled without overflowing
+struct Curve {
+    name: &'static str,
+    ln_f: fn(f64) -> f64,
+}
+
+const POLYNOMIALS: &[Curve] = &[
+    Curve { name: "n", ln_f: ln_n },
+    Curve { name: "n log n", ln_f: ln_n_log_n },
+    Curve { name: "n^2", ln_f: ln_n_squared },
+];
+
+// -------------------------------------------------------------------------- //
+
+fn ln_n(n: f64) -> f64 {
+    n.ln()
+}
+
+fn ln_n_log_n(n: f64) -> f64 {
+    n.ln() + n.ln().max(f64::EPSILON).ln()
+}
+
+fn ln_n_squared(n: f64) -> f64 {
+    2.0 * n.ln()
+}
+
+fn rms_relative_error(points: &[(usize, f64)], predict: impl Fn(f64) -> f64) -> f64 {
+    let sum: f64 = points.iter().map(|&(n, ms)| ((predict(n as f64) - ms) / ms).powi(2)).sum();
+    (sum / points.len() as f64).sqrt()
+}
+
+/// Fits `ms = a + b * f(n)`, the constant soaking up fixed costs, minimising the
+/// relative rather than the absolute error so the small sizes count as much
+fn fit_polynomial(points: &[(usize, f64)], ln_f: fn(f64) -> f64) -> f64 {
+    let ln_max = ln_f(points.iter().map(|p| p.0).max().unwrap() as f64);
+    let x = |n: f64| (ln_f(n) - ln_max).exp();
+
+    let (mut sw, mut sx, mut sxx, mut sy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for &(n, ms) in points {
+        let (w, x) = (1.0 / (ms * ms), x(n as f64));
+        sw += w;
+        sx += w * x;
+        sxx += w * x * x;
+        sy += w * ms;
+        sxy += w * x * ms;
+    }
+
+    let mut b = (sw * sxy - sx * sy) / (sw * sxx - sx * sx);
+    let mut a = (sy - b * sx) / sw;
+    if !b.is_finite() || b < 0.0 {
+        (a, b) = (sy / sw, 0.0);
+    } else if a < 0.0 {
+        (a, b) = (0.0, sxy / sxx);
+    }
+
+    rms_relative_error(points, |n| a + b * x(n))
+}
+
+/// Fits `ms = c * base^n` by a straight line through `ln ms`, returning the base and error
+fn fit_exponential(points: &[(usize, f64)]) -> (f64, f64) {
+    let count = points.len() as f64;
+    let mean_n = points.iter().map(|p| p.0 as f64).sum::<f64>() / count;
+    let mean_ln = points.iter().map(|p| p.1.ln()).sum::<f64>() / count;
+
+    let covariance: f64 = points.iter().map(|&(n, ms)| (n as f64 - mean_n) * (ms.ln() - mean_ln)).sum();
+    let variance: f64 = points.iter().map(|&(n, _)| (n as f64 - mean_n).powi(2)).sum();
+    let slope = covariance / variance;
+    let intercept = mean_ln - slope * mean_n;
+
+    (slope.exp(), rms_relative_error(points, |n| (intercept + slope * n).exp()))
+}
+
+/// Every candidate curve, best fit first
+fn fits(points: &[(usize, f64)]) -> Vec<Fit> {
+    let mut fits: Vec<Fit> = POLYNOMIALS.iter()
+        .map(|c| Fit { curve: c.name.to_string(), error: fit_polynomial(points, c.ln_f) })
+        .collect();
+
+    let (base, error) = fit_exponential(points);
+    fits.push(Fit { curve: format!("{:.2}^n", base), error });
+
+    fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+    fits
+}
+
+fn sizes(from: usize, factor: f64, steps: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = (0..steps)
+        .map(|i| (from as f64 * factor.powi(i as i32)).round() as usize)
+        .collect();
+    sizes.dedup();
+    sizes
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut from = 10usize;
+    let mut factor = 1.5f64;
+    let mut steps = 10usize;
+    let mut runs = 3usize;
+    let mut seed = 1u64;
+    let mut options = RunOptions { timeout: Some(Duration::from_secs(1)) };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--from" => from = iter.next().and_then(|f| f.parse().ok()).filter(|&f| f > 0)
+                .ok_or("scale: --from needs a positive size")?,
+            "--factor" => factor = iter.next().and_then(|f| f.parse().ok()).filter(|f: &f64| *f > 1.0)
+                .ok_or("scale: --factor needs a number above 1")?,
+            "--steps" => steps = iter.next().and_then(|s| s.parse().ok()).filter(|&s| s >= 3)
+                .ok_or("scale: --steps needs a number of at least 3")?,
+            "--runs" => runs = iter.next().and_then(|r| r.parse().ok()).filter(|&r| r > 0)
+                .ok_or("scale: --runs needs a positive number")?,
+            "--seed" => seed = iter.next().and_then(|s| s.parse().ok())
+                .ok_or("scale: --seed needs a number")?,
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, rest) = crate::parse_day("scale", &positional)?;
+    if let Some(extra) = rest.first() {
+        return Err(format!("scale: unexpected argument '{}'", extra));
+    }
+    let params = config.params(day.year, day.day, "");
+
+    let sizes = sizes(from, factor, steps);
+    let mut series: Vec<Series> = Vec::new();
+
+    for &size in &sizes {
+        let input = (day.generate)(size, &mut Rng::new(seed))
+            .ok_or(format!("scale: {} day {} has no input generator", day.year, day.day))?;
+        eprintln!("size {}, {} bytes", size, input.len());
+
+        let mut fastest: Vec<(u8, &'static str, Option<f64>)> = Vec::new();
+        for _ in 0..runs {
+            let results = catch_panic(|| (day.run_strategies)(&input, &params, options))
+                .map_err(|e| format!("scale: solving size {} panicked: {}", size, e))?;
+            let timed_out = results.iter().any(|r| matches!(r.answer, Answer::TimedOut(_)));
+
+            for r in results {
+                let ms = match r.answer {
+                    Answer::TimedOut(_) => None,
+                    _ => Some(millis(r.time)),
+                };
+                match fastest.iter_mut().find(|f| f.0 == r.part && f.1 == r.name) {
+                    Some(f) => f.2 = f.2.zip(ms).map(|(a, b)| a.min(b)),
+                    None => fastest.push((r.part, r.name, ms)),
+                }
+            }
+
+            // Another run would only sit through the same timeout again
+            if timed_out {
+                break;
+            }
+        }
+
+        for (part, name, ms) in fastest {
+            let s = match series.iter_mut().position(|s| s.part == part && s.name == name) {
+                Some(i) => &mut series[i],
+                None => {
+                    series.push(Series { part, name, points: Vec::new(), timed_out_at: None });
+                    series.last_mut().unwrap()
+                }
+            };
+            match ms {
+                _ if s.timed_out_at.is_some() => {}
+                Some(ms) => s.points.push((size, ms.max(1e-6))),
+                None => s.timed_out_at = Some(size),
+            }
+        }
+
+        // No point in growing further once nothing finishes
+        if series.iter().all(|s| s.timed_out_at.is_some()) {
+            break;
+        }
+    }
+
+    println!("{} day {}, fastest of {} runs", day.year, day.day, runs);
+    print!("{:>8}", "size");
+    for s in &series {
+        print!("  {:>20}", format!("part {} {}", s.part, s.name));
+    }
+    println!();
+    for &size in &sizes {
+        print!("{:>8}", size);
+        for s in &series {
+            let cell = match s.points.iter().find(|p| p.0 == size) {
+                Some((_, ms)) => format!("{:.4} ms", ms),
+                None if s.timed_out_at.is_some_and(|t| t <= size) => String::from("timed out"),
+                None => String::from("-"),
+            };
+            print!("  {:>20}", cell);
+        }
+        println!();
+    }
+    println!();
+
+    for s in &series {
+        let label = format!("part {} {}", s.part, s.name);
+        let timed_out = s.timed_out_at.map(|t| format!(", timed out from size {}", t)).unwrap_or_default();
+
+        if s.points.len() < 3 {
+            println!("{:<20} too few sizes finished to fit{}", label, timed_out);
+            continue;
+        }
+        let fits = fits(&s.points);
+        let errors: Vec<String> = fits.iter().map(|f| format!("{} {:.1}%", f.curve, f.error * 100.0)).collect();
+        println!("{:<20} {:<10} (error {}){}", label, fits[0].curve, errors.join(", "), timed_out);
+    }
+
+    Ok(())
+}
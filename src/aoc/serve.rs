@@ -0,0 +1,180 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+use advent_of_code::config::Config;
+use advent_of_code::{find_day, DEFAULT_YEAR};
+use advent_of_code::input::{self, LoadOptions};
+use advent_of_code::json::Json;
+use advent_of_code::solution::{catch_panic, millis, Answer, RunOptions};
+
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+}
+
+/// Largest input accepted, well above any real puzzle input
+const MAX_BODY: usize = 4 << 20;
+
+/// Room for the request line and headers on top of the body
+const MAX_HEAD: u64 = 64 << 10;
+
+/// How long a client may keep a read or write waiting, as connections are served one at a time
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// -------------------------------------------------------------------------- //
+
+fn error(status: &'static str, message: &str) -> Response {
+    Response { status, body: Json::object([("error", Json::from(message))]).to_string() }
+}
+
+fn read_failed(e: io::Error) -> Response {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => error("408 Request Timeout", "timed out reading the request"),
+        _ => error("400 Bad Request", &e.to_string()),
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let bad_request = |message: &str| error("400 Bad Request", message);
+    let mut reader = BufReader::new(stream.take(MAX_HEAD + MAX_BODY as u64));
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).map_err(read_failed)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or_else(|| bad_request("empty request"))?.to_string();
+    let path = parts.next().ok_or_else(|| bad_request("missing request path"))?.to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).map_err(read_failed)? == 0 {
+            return Err(bad_request("request headers too long or cut short"));
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| bad_request("invalid Content-Length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(error("413 Payload Too Large", &format!("inputs are limited to {} bytes", MAX_BODY)));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).map_err(read_failed)?;
+
+    Ok(Request { method, path, body })
+}
+
+/// Matches `/day/{n}/part/{p}` and `/{year}/day/{n}/part/{p}`
+fn parse_route(path: &str) -> Option<(u16, u8, u8)> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments[..] {
+        ["day", day, "part", part] => Some((DEFAULT_YEAR, day.parse().ok()?, part.parse().ok()?)),
+        [year, "day", day, "part", part] => Some((year.parse().ok()?, day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+fn handle(request: &Request, config: &Config, options: RunOptions) -> Response {
+    let (year, day_num, part) = match parse_route(&request.path) {
+        Some(route) => route,
+        None => return error("404 Not Found", "expected POST [/{year}]/day/{n}/part/{p}"),
+    };
+    if request.method != "POST" {
+        return error("405 Method Not Allowed", "only POST is supported");
+    }
+    let day = match find_day(year, day_num) {
+        Some(d) => d,
+        None => return error("404 Not Found", &format!("no solution for {} day {}", year, day_num)),
+    };
+    if part != 1 && part != 2 {
+        return error("404 Not Found", &format!("no part {}", part));
+    }
+
+    let input = input::normalise(&request.body, LoadOptions::default());
+    let params = config.params(year, day_num, "");
+
+    match catch_panic(|| (day.run_part)(&input, &params, part, options)) {
+        Ok(run) => {
+            if let Answer::TimedOut(progress) = &run.answer {
+                return error("504 Gateway Timeout", &format!("timed out ({})", progress));
+            }
+            if let Answer::Overflow(overflow) = &run.answer {
+                return error("422 Unprocessable Entity", &overflow.to_string());
+            }
+            let answer = match run.answer {
+                Answer::Unsolved => Json::Null,
+                a => Json::from(a.to_string()),
+            };
+            let body = Json::object([
+                ("year", Json::from(year)),
+                ("day", Json::from(day_num)),
+                ("part", Json::from(part)),
+                ("answer", answer),
+                ("parse_ms", Json::from(millis(run.parse))),
+                ("solve_ms", Json::from(millis(run.solve))),
+            ]);
+            Response { status: "200 OK", body: body.to_string() }
+        }
+        Err(msg) => error("500 Internal Server Error", &format!("solver panicked: {}", msg)),
+    }
+}
+
+fn serve_connection(mut stream: TcpStream, config: &Config, options: RunOptions) {
+    let timeouts = stream.set_read_timeout(Some(IO_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT)));
+    if let Err(e) = timeouts {
+        eprintln!("serve: cannot set timeouts: {}", e);
+        return;
+    }
+
+    let response = match read_request(&mut stream) {
+        Ok(request) => handle(&request, config, options),
+        Err(response) => response,
+    };
+
+    let _ = write!(stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status, response.body.len(), response.body);
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut port: u16 = 8023;
+    let mut options = RunOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--port" => {
+                port = iter.next().and_then(|p| p.parse().ok())
+                    .ok_or("serve: --port needs a port number")?;
+            }
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => return Err(format!("serve: unexpected argument '{}'", arg)),
+        }
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port)).map_err(|e| format!("cannot listen on port {}: {}", port, e))?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+    println!("listening on http://{}", addr);
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(s) => serve_connection(s, config, options),
+            Err(e) => eprintln!("serve: connection failed: {}", e),
+        }
+    }
+    Ok(())
+}
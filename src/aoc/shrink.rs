@@ -0,0 +1,148 @@
+use std::fs;
+use std::io::{self, Write};
+use std::time::Duration;
+use advent_of_code::config::{Config, Params};
+use advent_of_code::input;
+use advent_of_code::solution::{catch_panic, Answer, Day, RunOptions};
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Predicate {
+    Panic,
+    Disagree,
+}
+
+/// What went wrong running every strategy on an input
+#[derive(Debug, PartialEq)]
+enum Failure {
+    /// The panic message, without its digits as indices and lengths shift while shrinking
+    Panic(String),
+    /// Parts on which the strategies that finished gave different answers
+    Disagree(Vec<u8>),
+}
+
+// -------------------------------------------------------------------------- //
+
+fn failure(day: &Day, input: &[u8], params: &Params, options: RunOptions) -> Option<Failure> {
+    let runs = match catch_panic(|| (day.run_strategies)(input, params, options)) {
+        Ok(runs) => runs,
+        Err(message) => return Some(Failure::Panic(message.chars().filter(|c| !c.is_ascii_digit()).collect())),
+    };
+
+    let mut parts: Vec<u8> = runs.iter().map(|r| r.part).collect();
+    parts.dedup();
+    parts.retain(|&part| {
+        let answers: Vec<&Answer> = runs.iter()
+            .filter(|r| r.part == part && !matches!(r.answer, Answer::TimedOut(_)))
+            .map(|r| &r.answer)
+            .collect();
+        answers.windows(2).any(|w| w[0] != w[1])
+    });
+
+    (!parts.is_empty()).then_some(Failure::Disagree(parts))
+}
+
+/// Whether `candidate` still fails the way the original input did
+fn same_failure(original: &Failure, candidate: Option<Failure>) -> bool {
+    match (original, candidate) {
+        (Failure::Panic(a), Some(Failure::Panic(b))) => *a == b,
+        (Failure::Disagree(a), Some(Failure::Disagree(b))) => b.iter().any(|part| a.contains(part)),
+        _ => false,
+    }
+}
+
+/// The pinned lines along with the `kept` ones, in their original order
+fn assemble(lines: &[&[u8]], pinned: &[bool], kept: &[usize]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if pinned[i] || kept.binary_search(&i).is_ok() {
+            out.extend_from_slice(line);
+            out.push(b'\n');
+        }
+    }
+    out
+}
+
+/// Zeller's ddmin over the removable lines: drops ever smaller chunks of them, keeping
+/// every drop after which the input still fails, until no single line can go
+fn ddmin(mut kept: Vec<usize>, mut fails: impl FnMut(&[usize]) -> bool) -> Vec<usize> {
+    let mut chunks = 2;
+
+    while !kept.is_empty() {
+        let chunk = kept.len().div_ceil(chunks);
+        let reduced = (0..kept.len()).step_by(chunk).find_map(|start| {
+            let candidate: Vec<usize> = kept[..start].iter().chain(&kept[(start + chunk).min(kept.len())..])
+                .copied().collect();
+            fails(&candidate).then_some(candidate)
+        });
+
+        match reduced {
+            Some(candidate) => {
+                kept = candidate;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunk == 1 => break,
+            None => chunks = (chunks * 2).min(kept.len()),
+        }
+    }
+    kept
+}
+
+pub fn main(args: &[String], config: &Config) -> Result<(), String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut predicate: Option<Predicate> = None;
+    let mut out_path: Option<String> = None;
+    let mut options = RunOptions { timeout: Some(Duration::from_secs(5)) };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--predicate" => predicate = Some(match iter.next().map(String::as_str) {
+                Some("panic") => Predicate::Panic,
+                Some("disagree") => Predicate::Disagree,
+                _ => return Err(String::from("shrink: --predicate must be panic or disagree")),
+            }),
+            "--out" => out_path = iter.next().cloned(),
+            "--timeout" => options.timeout = Some(crate::parse_timeout(iter.next())?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, path) = match crate::parse_day("shrink", &positional)? {
+        (day, [path]) => (day, *path),
+        (_, []) => return Err(String::from("shrink: missing <input>")),
+        (_, [_, extra, ..]) => return Err(format!("shrink: unexpected argument '{}'", extra)),
+    };
+    let predicate = predicate.ok_or("shrink: missing --predicate panic|disagree")?;
+
+    let bytes = input::load(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+    let params = config.params(day.year, day.day, path);
+
+    let original = match (predicate, failure(day, &bytes, &params, options)) {
+        (Predicate::Panic, Some(f @ Failure::Panic(_))) => f,
+        (Predicate::Disagree, Some(f @ Failure::Disagree(_))) => f,
+        (Predicate::Panic, _) => return Err(format!("shrink: {} does not make day {} panic", path, day.day)),
+        (Predicate::Disagree, _) => return Err(format!("shrink: the strategies of day {} agree on {}", day.day, path)),
+    };
+    eprintln!("{} fails with {:?}", path, original);
+
+    let lines: Vec<&[u8]> = input::lines(&bytes).collect();
+    let pinned: Vec<bool> = lines.iter().enumerate().map(|(i, line)| (day.pinned_line)(i, line)).collect();
+    let removable: Vec<usize> = (0..lines.len()).filter(|&i| !pinned[i]).collect();
+
+    let mut tests = 0;
+    let kept = ddmin(removable, |candidate| {
+        tests += 1;
+        let input = assemble(&lines, &pinned, candidate);
+        same_failure(&original, failure(day, &input, &params, options))
+    });
+
+    let shrunk = assemble(&lines, &pinned, &kept);
+    match &out_path {
+        Some(out) => fs::write(out, &shrunk).map_err(|e| format!("cannot write {}: {}", out, e))?,
+        None => io::stdout().write_all(&shrunk).map_err(|e| e.to_string())?,
+    }
+    eprintln!("shrunk {} lines to {} in {} runs", lines.len(), input::lines(&shrunk).count(), tests);
+
+    Ok(())
+}
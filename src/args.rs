@@ -0,0 +1,203 @@
+use std::fmt::Write;
+use std::time::Duration;
+use crate::config::Value;
+
+
+/// What a day parameter takes, on the command line and in `aoc.toml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Int,
+    /// An integer that may not be negative
+    Uint,
+    /// This many comma separated non-negative integers, as in `--limits 12,13,14`
+    Uints(usize),
+    Str,
+}
+
+/// A command-line option a day declares to override one of its parameters, taking
+/// precedence over `aoc.toml`
+#[derive(Debug, Clone, Copy)]
+pub struct DayOption {
+    /// Name without the leading dashes
+    pub name: &'static str,
+    /// Parameter it sets, as looked up by the day's `parse`
+    pub param: &'static str,
+    pub kind: Kind,
+    pub help: &'static str,
+}
+
+/// Parsed command line of a day binary
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Args {
+    pub help: bool,
+    pub input: Option<String>,
+    pub part: Option<u8>,
+    pub verbose: bool,
+    /// Time budget for each part
+    pub timeout: Option<Duration>,
+    /// Print the parsed model as JSON instead of solving
+    pub dump_model: bool,
+    /// Values of the day-specific options that were given, by parameter
+    pub overrides: Vec<(&'static str, Value)>,
+}
+
+/// Options every day understands, as (flag, value, help)
+const COMMON: &[(&str, &str, &str)] = &[
+    ("input", "<file>", "puzzle input, also accepted as the only positional argument"),
+    ("part", "<1|2>", "solve only one of the parts"),
+    ("verbose", "", "show parse and solve times and the day's visualisation, if any"),
+    ("timeout", "<secs>", "give up on a part after this long and show how far it got"),
+    ("dump-model", "json", "print the parsed model as JSON instead of solving"),
+    ("help", "", "show this message"),
+];
+
+// -------------------------------------------------------------------------- //
+
+impl Kind {
+    fn metavar(self) -> String {
+        match self {
+            Kind::Int | Kind::Uint => String::from("<n>"),
+            Kind::Uints(count) => format!("<{}>", vec!["n"; count].join(",")),
+            Kind::Str => String::from("<text>"),
+        }
+    }
+
+    fn parse(self, text: &str) -> Option<Value> {
+        let int = |s: &str| s.trim().replace('_', "").parse().ok().map(Value::Int);
+        let uint = |s: &str| int(s).filter(|v| matches!(v, Value::Int(n) if *n >= 0));
+        match self {
+            Kind::Int => int(text),
+            Kind::Uint => uint(text),
+            Kind::Uints(count) => text.split(',').map(uint).collect::<Option<Vec<Value>>>()
+                .filter(|values| values.len() == count)
+                .map(Value::Array),
+            Kind::Str => Some(Value::Str(text.to_string())),
+        }
+    }
+
+    /// Whether a value from `aoc.toml` is of this kind
+    pub fn accepts(self, value: &Value) -> bool {
+        match (self, value) {
+            (Kind::Int, Value::Int(_)) | (Kind::Str, Value::Str(_)) => true,
+            (Kind::Uint, Value::Int(n)) => *n >= 0,
+            (Kind::Uints(count), Value::Array(values)) => {
+                values.len() == count && values.iter().all(|v| Kind::Uint.accepts(v))
+            }
+            _ => false,
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Kind::Int => String::from("an integer"),
+            Kind::Uint => String::from("a non-negative integer"),
+            Kind::Uints(count) => format!("{} comma separated non-negative integers", count),
+            Kind::Str => String::from("a value"),
+        }
+    }
+}
+
+/// Levenshtein distance, to suggest the option a typo was meant to be
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn unknown(flag: &str, options: &[DayOption]) -> String {
+    let name = flag.trim_start_matches('-');
+    let closest = COMMON.iter().map(|c| c.0).chain(options.iter().map(|o| o.name))
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .min();
+
+    match closest {
+        Some((distance, candidate)) if distance <= 2 => {
+            format!("unknown option '{}', did you mean '--{}'?", flag, candidate)
+        }
+        _ => format!("unknown option '{}'", flag),
+    }
+}
+
+/// A `--timeout` in seconds, which may be fractional
+pub fn parse_timeout(text: &str) -> Result<Duration, String> {
+    text.parse::<f64>().ok()
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .map(Duration::from_secs_f64)
+        .ok_or(String::from("--timeout needs a positive number of seconds"))
+}
+
+/// Parses the arguments after the program name. Values go either after the flag
+/// or after an `=`, as in `--part 2` and `--part=2`.
+pub fn parse(args: &[String], options: &[DayOption]) -> Result<Args, String> {
+    let mut parsed = Args::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || inline.map(str::to_string).or_else(|| iter.next().cloned())
+            .ok_or(format!("{} needs a value", flag));
+
+        match flag {
+            "--help" | "-h" | "--verbose" | "-v" if inline.is_some() => {
+                return Err(format!("{} does not take a value", flag));
+            }
+            "--help" | "-h" => parsed.help = true,
+            "--verbose" | "-v" => parsed.verbose = true,
+            "--input" => parsed.input = Some(value()?),
+            "--part" => parsed.part = Some(match value()?.as_str() {
+                "1" => 1,
+                "2" => 2,
+                other => return Err(format!("--part must be 1 or 2, got '{}'", other)),
+            }),
+            "--timeout" => parsed.timeout = Some(parse_timeout(&value()?)?),
+            "--dump-model" => match value()?.as_str() {
+                "json" => parsed.dump_model = true,
+                other => return Err(format!("--dump-model only supports json, got '{}'", other)),
+            },
+            _ if flag.starts_with('-') => {
+                let option = options.iter().find(|o| flag.strip_prefix("--") == Some(o.name))
+                    .ok_or_else(|| unknown(flag, options))?;
+                let text = value()?;
+                let parsed_value = option.kind.parse(&text)
+                    .ok_or(format!("{} needs {}, got '{}'", flag, option.kind.describe(), text))?;
+                parsed.overrides.push((option.param, parsed_value));
+            }
+            _ if parsed.input.is_none() => parsed.input = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// The `--help` text of a day binary
+pub fn usage(program: &str, options: &[DayOption]) -> String {
+    let mut rows: Vec<(String, &str)> = COMMON.iter()
+        .map(|(name, metavar, help)| (format!("--{} {}", name, metavar), *help))
+        .collect();
+    let common = rows.len();
+    rows.extend(options.iter().map(|o| (format!("--{} {}", o.name, o.kind.metavar()), o.help)));
+    let width = rows.iter().map(|r| r.0.trim_end().len()).max().unwrap_or(0);
+
+    let mut out = format!("usage: {} [<input>] [options]\n\noptions:\n", program);
+    for (i, (flag, help)) in rows.iter().enumerate() {
+        if i == common {
+            out.push_str("\nday options:\n");
+        }
+        let _ = writeln!(out, "  {:<width$}  {}", flag.trim_end(), help, width = width);
+    }
+    out
+}
@@ -0,0 +1,359 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+
+/// Arbitrary-precision signed integer for answers that outgrow the fixed-width types.
+///
+/// Stored as sign and magnitude, the magnitude being little-endian base 2^32 limbs
+/// without trailing zeros (so zero is an empty, non-negative magnitude).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    mag: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+// -------------------------------------------------------------------------- //
+
+fn trim(mag: &mut Vec<u32>) {
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x.cmp(y);
+        }
+    }
+    Ordering::Equal
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+
+    for (i, &x) in long.iter().enumerate() {
+        let sum = x as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// `a - b`, requires `a >= b`
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut diff = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        }
+        out.push(diff as u32);
+    }
+    assert_eq!(borrow, 0);
+    trim(&mut out);
+    out
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = out[i + j] as u64 + x as u64 * y as u64 + carry;
+            out[i + j] = cur as u32;
+            carry = cur >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    trim(&mut out);
+    out
+}
+
+fn divmod_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut out = vec![0u32; a.len()];
+    let mut rem = 0u64;
+
+    for i in (0..a.len()).rev() {
+        let cur = (rem << 32) | a[i] as u64;
+        out[i] = (cur / d as u64) as u32;
+        rem = cur % d as u64;
+    }
+    trim(&mut out);
+    (out, rem as u32)
+}
+
+fn shl1_or(mag: &mut Vec<u32>, bit: u32) {
+    let mut carry = bit;
+    for limb in mag.iter_mut() {
+        let next = *limb >> 31;
+        *limb = (*limb << 1) | carry;
+        carry = next;
+    }
+    if carry > 0 {
+        mag.push(carry);
+    }
+}
+
+/// Binary long division on magnitudes
+fn divmod_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "division by zero");
+
+    if b.len() == 1 {
+        let (q, r) = divmod_small(a, b[0]);
+        let mut r = vec![r];
+        trim(&mut r);
+        return (q, r);
+    }
+    if cmp_mag(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+
+    let mut q = vec![0u32; a.len()];
+    let mut r: Vec<u32> = Vec::new();
+
+    for i in (0..a.len() * 32).rev() {
+        shl1_or(&mut r, (a[i / 32] >> (i % 32)) & 1);
+        if cmp_mag(&r, b) != Ordering::Less {
+            r = sub_mag(&r, b);
+            q[i / 32] |= 1 << (i % 32);
+        }
+    }
+    trim(&mut q);
+    (q, r)
+}
+
+// -------------------------------------------------------------------------- //
+
+impl BigInt {
+    fn from_parts(negative: bool, mut mag: Vec<u32>) -> BigInt {
+        trim(&mut mag);
+        BigInt { negative: negative && !mag.is_empty(), mag }
+    }
+
+    pub fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt { negative: false, mag: self.mag.clone() }
+    }
+
+    /// Truncating division, with the remainder taking the sign of `self` like the primitive types
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = divmod_mag(&self.mag, &other.mag);
+        (
+            BigInt::from_parts(self.negative != other.negative, q),
+            BigInt::from_parts(self.negative, r),
+        )
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.mag.len() > 4 {
+            return None;
+        }
+        let mut mag = 0u128;
+        for (i, &limb) in self.mag.iter().enumerate() {
+            mag |= (limb as u128) << (32 * i);
+        }
+        if self.negative {
+            if mag <= i128::MAX as u128 + 1 {
+                Some((mag as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(mag).ok()
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_i128().and_then(|n| u64::try_from(n).ok())
+    }
+
+    /// Parses an optionally signed ASCII decimal straight from input bytes
+    pub fn parse_bytes(bytes: &[u8]) -> Result<BigInt, ParseBigIntError> {
+        let (negative, digits) = match bytes.strip_prefix(b"-") {
+            Some(rest) => (true, rest),
+            None => (false, bytes.strip_prefix(b"+").unwrap_or(bytes)),
+        };
+        if digits.is_empty() || !digits.iter().all(|c| c.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut mag: Vec<u32> = Vec::new();
+        let head = digits.len() % DECIMAL_CHUNK_DIGITS;
+        let chunks = std::iter::once(&digits[..head])
+            .chain(digits[head..].chunks(DECIMAL_CHUNK_DIGITS));
+
+        for chunk in chunks.filter(|c| !c.is_empty()) {
+            let value = chunk.iter().fold(0u32, |n, c| n * 10 + (c - b'0') as u32);
+            mag = mul_mag(&mag, &[10u32.pow(chunk.len() as u32)]);
+            mag = add_mag(&mag, &[value]);
+            trim(&mut mag);
+        }
+
+        Ok(BigInt::from_parts(negative, mag))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> BigInt {
+        let mag = (0..4).map(|i| (n >> (32 * i)) as u32).collect();
+        BigInt::from_parts(false, mag)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> BigInt {
+        let mut b = BigInt::from(n.unsigned_abs());
+        b.negative = n < 0;
+        b
+    }
+}
+
+macro_rules! bigint_from {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(n: $t) -> BigInt {
+                BigInt::from(n as i128)
+            }
+        })*
+    };
+}
+
+bigint_from!(i32, i64, u32, u64, usize);
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<BigInt, ParseBigIntError> {
+        BigInt::parse_bytes(s.as_bytes())
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = divmod_small(&mag, DECIMAL_CHUNK);
+            chunks.push(r);
+            mag = q;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.last().unwrap())?;
+        for c in chunks.iter().rev().skip(1) {
+            write!(f, "{:09}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.mag)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &other.mag));
+        }
+        match cmp_mag(&self.mag, &other.mag) {
+            Ordering::Less => BigInt::from_parts(other.negative, sub_mag(&other.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &other.mag)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &(-other.clone())
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(self.negative != other.negative, mul_mag(&self.mag, &other.mag))
+    }
+}
+
+macro_rules! bigint_owned_op {
+    ($($tr:ident $f:ident),*) => {
+        $(impl $tr for BigInt {
+            type Output = BigInt;
+
+            fn $f(self, other: BigInt) -> BigInt {
+                (&self).$f(&other)
+            }
+        })*
+    };
+}
+
+bigint_owned_op!(Add add, Sub sub, Mul mul);
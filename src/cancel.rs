@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+
+/// Cooperative cancellation token handed to the solvers, which are
+/// expected to poll it from their hot loops
+#[derive(Debug, Clone)]
+pub struct Cancel {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Returned by a solver that gave up, with a description of how far it got
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedOut {
+    pub progress: String,
+}
+
+// -------------------------------------------------------------------------- //
+
+impl Cancel {
+    pub fn never() -> Cancel {
+        Cancel { deadline: None, cancelled: Arc::new(AtomicBool::new(false)) }
+    }
+
+    pub fn after(timeout: Option<Duration>) -> Cancel {
+        Cancel {
+            deadline: timeout.map(|t| Instant::now() + t),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        match self.deadline {
+            Some(d) if Instant::now() >= d => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// `Err` with the progress description once cancelled
+    pub fn check<F: FnOnce() -> String>(&self, progress: F) -> Result<(), TimedOut> {
+        if self.is_cancelled() {
+            Err(TimedOut { progress: progress() })
+        } else {
+            Ok(())
+        }
+    }
+}
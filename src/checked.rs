@@ -0,0 +1,104 @@
+use std::fmt;
+use std::ops::{Add, Mul, Shl, Sub};
+
+
+/// Arithmetic in a solution that went out of range, caught by the `checked` feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub year: u16,
+    pub day: u8,
+    /// The computation that overflowed, as written in the solution
+    pub computation: &'static str,
+}
+
+/// Integer arithmetic for one day's solution. Built with the `checked` feature an
+/// overflow comes back as an [`Overflow`], without it these are the plain operators,
+/// which panic in debug builds and silently wrap in release ones.
+#[derive(Debug, Clone, Copy)]
+pub struct Arith {
+    year: u16,
+    day: u8,
+}
+
+pub trait Int: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Shl<u32, Output = Self> {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// `self * 2^bits`, or `None` if any set bit would be shifted out
+    fn checked_shl(self, bits: u32) -> Option<Self>;
+}
+
+// -------------------------------------------------------------------------- //
+
+macro_rules! int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_shl(self, bits: u32) -> Option<$t> {
+                let shifted = <$t>::checked_shl(self, bits)?;
+                (shifted >> bits == self).then_some(shifted)
+            }
+        })*
+    };
+}
+
+int!(i32, i64, i128, u32, u64, usize);
+
+impl Arith {
+    pub const fn new(year: u16, day: u8) -> Arith {
+        Arith { year, day }
+    }
+
+    fn overflow(self, computation: &'static str) -> Overflow {
+        Overflow { year: self.year, day: self.day, computation }
+    }
+
+    pub fn add<T: Int>(self, a: T, b: T, computation: &'static str) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_add(b).ok_or_else(|| self.overflow(computation))
+        } else {
+            Ok(a + b)
+        }
+    }
+
+    pub fn sub<T: Int>(self, a: T, b: T, computation: &'static str) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_sub(b).ok_or_else(|| self.overflow(computation))
+        } else {
+            Ok(a - b)
+        }
+    }
+
+    pub fn mul<T: Int>(self, a: T, b: T, computation: &'static str) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_mul(b).ok_or_else(|| self.overflow(computation))
+        } else {
+            Ok(a * b)
+        }
+    }
+
+    pub fn shl<T: Int>(self, a: T, bits: u32, computation: &'static str) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_shl(bits).ok_or_else(|| self.overflow(computation))
+        } else {
+            Ok(a << bits)
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}: {} overflowed", self.year, self.day, self.computation)
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::args::Kind;
+use crate::hash::FxHashMap;
+use crate::{find_day, DEFAULT_YEAR};
+
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+    Bool(bool),
+    Array(Vec<Value>),
+}
+
+/// A parameter a day reads, declared so that `aoc.toml` can be checked against it
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub key: &'static str,
+    pub kind: Kind,
+}
+
+/// Per-day parameter overrides, looked up by the solvers with their own defaults
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: FxHashMap<String, Value>,
+}
+
+/// Parsed `aoc.toml`, a small subset of TOML:
+///
+/// ```toml
+/// [day11]
+/// part2_expansion = 999_999
+///
+/// [day11.alice]         # only applies to inputs named alice.txt
+/// part2_expansion = 99
+///
+/// [2022.day5]           # days of other years than the default one
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    sections: FxHashMap<(u16, u8, Option<String>), Params>,
+}
+
+// -------------------------------------------------------------------------- //
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: Value) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    pub fn int(&self, key: &str, default: i64) -> i64 {
+        match self.get(key) {
+            Some(Value::Int(n)) => *n,
+            Some(v) => panic!("parameter '{}' must be an integer, got {:?}", key, v),
+            None => default,
+        }
+    }
+
+    pub fn uint(&self, key: &str, default: usize) -> usize {
+        match self.get(key) {
+            Some(Value::Int(n)) => usize::try_from(*n)
+                .unwrap_or_else(|_| panic!("parameter '{}' must not be negative, got {}", key, n)),
+            Some(v) => panic!("parameter '{}' must be an integer, got {:?}", key, v),
+            None => default,
+        }
+    }
+
+    pub fn string(&self, key: &str, default: &str) -> String {
+        match self.get(key) {
+            Some(Value::Str(s)) => s.clone(),
+            Some(v) => panic!("parameter '{}' must be a string, got {:?}", key, v),
+            None => default.to_string(),
+        }
+    }
+
+    pub fn uints(&self, key: &str, default: &[usize]) -> Vec<usize> {
+        match self.get(key) {
+            Some(Value::Array(values)) => values.iter().map(|v| match v {
+                Value::Int(n) if *n >= 0 => *n as usize,
+                _ => panic!("parameter '{}' must be an array of non-negative integers, got {:?}", key, v),
+            }).collect(),
+            Some(v) => panic!("parameter '{}' must be an array of non-negative integers, got {:?}", key, v),
+            None => default.to_vec(),
+        }
+    }
+
+    fn merge(&mut self, other: &Params) {
+        for (k, v) in &other.values {
+            self.values.insert(k.clone(), v.clone());
+        }
+    }
+}
+
+impl Config {
+    /// Loads `aoc.toml` from the working directory, if there is one, and checks its
+    /// parameters against the ones the days declare
+    pub fn load() -> Result<Config, String> {
+        match fs::read_to_string(CONFIG_FILE) {
+            Ok(text) => Config::parse(&text).and_then(|config| config.check().map(|_| config))
+                .map_err(|e| format!("{}: {}", CONFIG_FILE, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("cannot read {}: {}", CONFIG_FILE, e)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        let mut section: Option<(u16, u8, Option<String>)> = None;
+
+        for (i, raw_line) in text.lines().enumerate() {
+            let line = strip_comment(raw_line).trim();
+            let line_no = i + 1;
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']')
+                    .ok_or(format!("line {}: unterminated section header", line_no))?;
+                section = Some(parse_section(header.trim())
+                    .ok_or(format!("line {}: expected [dayN], [dayN.input] or [YYYY.dayN]", line_no))?);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .ok_or(format!("line {}: expected key = value", line_no))?;
+            let key = key.trim();
+            if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("line {}: invalid key '{}'", line_no, key));
+            }
+            let value = parse_value(value.trim())
+                .ok_or(format!("line {}: invalid value for '{}'", line_no, key))?;
+
+            let current = section.clone()
+                .ok_or(format!("line {}: key '{}' outside of a [dayN] section", line_no, key))?;
+            config.sections.entry(current).or_default().set(key, value);
+        }
+
+        Ok(config)
+    }
+
+    /// Fails on the first parameter that its day does not read or that has the wrong
+    /// type. Sections for days without a solution are left alone.
+    pub fn check(&self) -> Result<(), String> {
+        let mut sections: Vec<_> = self.sections.iter().collect();
+        sections.sort_by(|a, b| a.0.cmp(b.0));
+
+        for ((year, day, input), params) in sections {
+            let Some(solution) = find_day(*year, *day) else { continue };
+            let declared = (solution.params)();
+            let section = section_name(*year, *day, input.as_deref());
+
+            let mut keys: Vec<&String> = params.values.keys().collect();
+            keys.sort();
+            for key in keys {
+                let value = &params.values[key];
+                match declared.iter().find(|p| p.key == key) {
+                    None => return Err(format!("{}: unknown parameter '{}'", section, key)),
+                    Some(param) if !param.kind.accepts(value) => {
+                        return Err(format!("{}: '{}' must be {}, got {}", section, key, expected(param.kind), value));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parameters for `day` of `year`, with overrides for the given input file applied on top
+    pub fn params(&self, year: u16, day: u8, input_path: &str) -> Params {
+        let mut params = Params::default();
+        if let Some(p) = self.sections.get(&(year, day, None)) {
+            params.merge(p);
+        }
+
+        let stem = Path::new(input_path).file_stem().and_then(|s| s.to_str());
+        if let Some(stem) = stem {
+            if let Some(p) = self.sections.get(&(year, day, Some(stem.to_string()))) {
+                params.merge(p);
+            }
+        }
+        params
+    }
+}
+
+impl fmt::Display for Value {
+    /// As it would be written in `aoc.toml`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Str(s) => write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Array(values) => {
+                let items: Vec<String> = values.iter().map(Value::to_string).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn section_name(year: u16, day: u8, input: Option<&str>) -> String {
+    let year = if year == DEFAULT_YEAR { String::new() } else { format!("{}.", year) };
+    match input {
+        Some(input) => format!("[{}day{}.{}]", year, day, input),
+        None => format!("[{}day{}]", year, day),
+    }
+}
+
+fn expected(kind: Kind) -> String {
+    match kind {
+        Kind::Int => String::from("an integer"),
+        Kind::Uint => String::from("a non-negative integer"),
+        Kind::Uints(count) => format!("an array of {} non-negative integers", count),
+        Kind::Str => String::from("a string"),
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_section(header: &str) -> Option<(u16, u8, Option<String>)> {
+    let (year, header) = match header.split_once('.') {
+        Some((y, rest)) if y.trim().len() == 4 && y.trim().bytes().all(|b| b.is_ascii_digit()) => {
+            (y.trim().parse().ok()?, rest.trim())
+        }
+        _ => (DEFAULT_YEAR, header),
+    };
+    let (day, input) = match header.split_once('.') {
+        Some((d, i)) => (d, Some(i.trim().trim_matches('"'))),
+        None => (header, None),
+    };
+    let day = day.trim().strip_prefix("day")?.parse().ok()?;
+
+    // Allow both [day3.alice] and [day3."alice.txt"]
+    let input = input.map(|i| {
+        Path::new(i).file_stem().and_then(|s| s.to_str()).unwrap_or(i).to_string()
+    });
+    Some((year, day, input))
+}
+
+/// A whole value, with nothing but whitespace after it
+fn parse_value(s: &str) -> Option<Value> {
+    let (value, rest) = value_prefix(s)?;
+    rest.trim().is_empty().then_some(value)
+}
+
+/// The value at the start of `s` and what follows it
+fn value_prefix(s: &str) -> Option<(Value, &str)> {
+    let s = s.trim_start();
+    if let Some(rest) = s.strip_prefix('"') {
+        return string_prefix(rest);
+    }
+    if let Some(rest) = s.strip_prefix('[') {
+        return array_prefix(rest);
+    }
+
+    let end = s.find(|c: char| c == ',' || c == ']' || c.is_whitespace()).unwrap_or(s.len());
+    let (token, rest) = s.split_at(end);
+    let value = match token {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::Int(token.replace('_', "").parse().ok()?),
+    };
+    Some((value, rest))
+}
+
+/// A string after its opening quote, with `\"`, `\\`, `\n` and `\t` escapes
+fn string_prefix(s: &str) -> Option<(Value, &str)> {
+    let mut out = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((Value::Str(out), &s[i + 1..])),
+            '\\' => out.push(match chars.next()?.1 {
+                '"' => '"',
+                '\\' => '\\',
+                'n' => '\n',
+                't' => '\t',
+                _ => return None,
+            }),
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// An array after its opening bracket. Elements are separated by single commas, with
+/// one more allowed before the closing bracket.
+fn array_prefix(s: &str) -> Option<(Value, &str)> {
+    let mut values = Vec::new();
+    let mut rest = s.trim_start();
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            return Some((Value::Array(values), after));
+        }
+        let (value, after) = value_prefix(rest)?;
+        values.push(value);
+
+        rest = after.trim_start();
+        match rest.strip_prefix(',') {
+            Some(after) => rest = after.trim_start(),
+            None => return Some((Value::Array(values), rest.strip_prefix(']')?)),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::ffi::{c_char, c_int};
+use std::fmt::Write;
+use std::slice;
+use crate::config::Params;
+use crate::input::{self, LoadOptions};
+use crate::solution::{catch_panic, Answer, RunOptions};
+use crate::{find_day, DEFAULT_YEAR};
+
+
+/// What a call into the library came to, returned to C as an `int`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    InvalidArgument = 1,
+    NoSuchDay = 2,
+    BufferTooSmall = 3,
+    Panicked = 4,
+    Overflowed = 5,
+    Unsolved = 6,
+}
+
+/// Every status with its name in the header and what it means there
+const STATUSES: &[(Status, &str, &str)] = &[
+    (Status::Ok, "AOC_OK", "The answer was written to out_buf"),
+    (Status::InvalidArgument, "AOC_INVALID_ARGUMENT", "A pointer was NULL or out_len was 0"),
+    (Status::NoSuchDay, "AOC_NO_SUCH_DAY", "There is no solution for that year, day or part"),
+    (Status::BufferTooSmall, "AOC_BUFFER_TOO_SMALL", "The answer and its NUL do not fit in out_len bytes"),
+    (Status::Panicked, "AOC_PANICKED", "The solver gave up on the input, out_buf holds why"),
+    (Status::Overflowed, "AOC_OVERFLOWED", "Arithmetic overflowed (checked builds), out_buf holds where"),
+    (Status::Unsolved, "AOC_UNSOLVED", "The part has no solution yet"),
+];
+
+// -------------------------------------------------------------------------- //
+
+/// Copies as much of `text` as fits into `out` followed by a NUL, returning
+/// whether all of it did
+fn write_c_string(out: &mut [u8], text: &str) -> bool {
+    let len = text.len().min(out.len() - 1);
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    out[len] = 0;
+    len == text.len()
+}
+
+/// [`aoc_solve`] for days of any year.
+///
+/// # Safety
+///
+/// As for [`aoc_solve`].
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    if input_ptr.is_null() || out_buf.is_null() || out_len == 0 {
+        return Status::InvalidArgument as c_int;
+    }
+    let out = slice::from_raw_parts_mut(out_buf.cast::<u8>(), out_len);
+
+    let day = match find_day(year, day).filter(|_| matches!(part, 1 | 2)) {
+        Some(day) => day,
+        None => {
+            write_c_string(out, &format!("no solution for {} day {} part {}", year, day, part));
+            return Status::NoSuchDay as c_int;
+        }
+    };
+    let input = input::normalise(slice::from_raw_parts(input_ptr, input_len), LoadOptions::default());
+
+    // The host's panic hook still sees the panic, and prints it to stderr by default
+    let solved = catch_panic(|| (day.run_part)(&input, &Params::default(), part, RunOptions::default()));
+
+    // Without a timeout the solvers run to the end, so never answer TimedOut
+    let (status, text) = match solved {
+        Ok(run) => match run.answer {
+            Answer::Overflow(overflow) => (Status::Overflowed, overflow.to_string()),
+            Answer::Unsolved => (Status::Unsolved, String::new()),
+            answer => (Status::Ok, answer.to_string()),
+        },
+        Err(message) => (Status::Panicked, message),
+    };
+
+    // Half an answer is worse than none, unlike half an error message
+    if !write_c_string(out, &text) && status == Status::Ok {
+        out[0] = 0;
+        return Status::BufferTooSmall as c_int;
+    }
+    status as c_int
+}
+
+/// Solves one part of a day of the default year, writing the answer to `out_buf` as
+/// a NUL-terminated string. Parameters are the defaults, `aoc.toml` is not read.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` to `out_len`
+/// writable ones. Neither may be touched by another thread during the call, but
+/// calls on different buffers may run on several threads at once.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    aoc_solve_year(DEFAULT_YEAR, day, part, input_ptr, input_len, out_buf, out_len)
+}
+
+/// The C header declaring the functions above, as committed in `include/aoc.h`
+pub fn header() -> String {
+    let mut out = String::from("\
+/* Generated from src/ffi.rs, do not edit. Regenerate with
+ * UPDATE_SNAPSHOTS=1 cargo test --features c-api --test ffi */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+");
+
+    for (status, name, meaning) in STATUSES {
+        writeln!(out, "/* {} */\n#define {} {}\n", meaning, name, *status as c_int).unwrap();
+    }
+
+    write!(out, "\
+/* Solves one part of a day of {year}, writing the answer to out_buf as a
+ * NUL-terminated string. input_ptr holds the puzzle input as it was downloaded.
+ * Parameters are the defaults, aoc.toml is not read. Returns one of the AOC_
+ * statuses above. Safe to call from several threads at once. */
+int aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t input_len,
+              char *out_buf, size_t out_len);
+
+/* aoc_solve for days of any year */
+int aoc_solve_year(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input_ptr,
+                   size_t input_len, char *out_buf, size_t out_len);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+", year = DEFAULT_YEAR).unwrap();
+    out
+}
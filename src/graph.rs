@@ -0,0 +1,324 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::Infallible;
+use std::hash::Hash;
+use crate::hash::FxHashMap;
+
+
+/// Hands out dense ids to keys in the order they are first seen, so that graphs
+/// over arbitrary node labels can be stored as plain vectors
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    ids: FxHashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge<W> {
+    pub to: usize,
+    pub weight: W,
+}
+
+/// Directed graph over dense ids, as adjacency lists kept in the order the edges were added
+#[derive(Debug, Clone)]
+pub struct Graph<W = ()> {
+    edges: Vec<Vec<Edge<W>>>,
+}
+
+/// Shape of the walk `x, f(x), f(f(x)), ...` through a functional graph: after
+/// `start` steps it enters a cycle that repeats every `len` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+// -------------------------------------------------------------------------- //
+
+impl<K> Default for Interner<K> {
+    fn default() -> Interner<K> {
+        Interner { ids: FxHashMap::default(), keys: Vec::new() }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Interner<K> {
+    pub fn new() -> Interner<K> {
+        Interner::default()
+    }
+
+    pub fn intern(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    pub fn get(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Ids and their keys, in id order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &K)> {
+        self.keys.iter().enumerate()
+    }
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Graph<W> {
+        Graph { edges: Vec::new() }
+    }
+}
+
+impl<W: Copy> Graph<W> {
+    pub fn new(nodes: usize) -> Graph<W> {
+        Graph { edges: (0..nodes).map(|_| Vec::new()).collect() }
+    }
+
+    pub fn add_node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    /// Adds an edge, growing the graph if either end is a new id
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        let needed = from.max(to) + 1;
+        if self.edges.len() < needed {
+            self.edges.resize_with(needed, Vec::new);
+        }
+        self.edges[from].push(Edge { to, weight });
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn edges(&self, node: usize) -> &[Edge<W>] {
+        &self.edges[node]
+    }
+
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[node].iter().map(|e| e.to)
+    }
+
+    /// Number of edges on the shortest path from `start` to every node, if it can be reached
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.len()];
+        let mut queue = VecDeque::from([start]);
+        dist[start] = Some(0);
+
+        while let Some(node) = queue.pop_front() {
+            let d = dist[node].unwrap();
+            for next in self.neighbours(node) {
+                if dist[next].is_none() {
+                    dist[next] = Some(d + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        dist
+    }
+
+    /// Nodes reachable from `start` in depth-first preorder, following edges in the order they were added
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+
+            // Reversed so that the first edge is popped first
+            stack.extend(self.edges[node].iter().rev().map(|e| e.to).filter(|&n| !visited[n]));
+        }
+        order
+    }
+
+    /// Tarjan's strongly connected components, each one coming before the components that
+    /// lead into it. Runs on an explicit stack so that long paths cannot overflow.
+    pub fn strongly_connected(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        // Pending calls, as the node and the next of its edges to look at
+        let mut calls: Vec<(usize, usize)> = Vec::new();
+
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+            calls.push((root, 0));
+
+            while let Some((node, mut edge)) = calls.pop() {
+                if edge == 0 {
+                    index[node] = next_index;
+                    low[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                } else {
+                    // Back from the call on the previous edge
+                    let callee = self.edges[node][edge - 1].to;
+                    low[node] = low[node].min(low[callee]);
+                }
+
+                let mut descended = false;
+                while edge < self.edges[node].len() {
+                    let next = self.edges[node][edge].to;
+                    edge += 1;
+
+                    if index[next] == UNVISITED {
+                        calls.push((node, edge));
+                        calls.push((next, 0));
+                        descended = true;
+                        break;
+                    } else if on_stack[next] {
+                        low[node] = low[node].min(index[next]);
+                    }
+                }
+                if descended || low[node] != index[node] {
+                    continue;
+                }
+
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+        components
+    }
+}
+
+impl<W: Copy + Into<u64>> Graph<W> {
+    /// Length of the lightest path from `start` to every node, if it can be reached
+    pub fn dijkstra(&self, start: usize) -> Vec<Option<u64>> {
+        let mut dist = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((0u64, start))]);
+
+        while let Some(Reverse((d, node))) = heap.pop() {
+            if dist[node].is_some() {
+                continue;
+            }
+            dist[node] = Some(d);
+
+            for e in &self.edges[node] {
+                if dist[e.to].is_none() {
+                    heap.push(Reverse((d + e.weight.into(), e.to)));
+                }
+            }
+        }
+        dist
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+/// Floyd's tortoise and hare on the walk from `start`
+pub fn floyd<T: Copy + PartialEq>(start: T, mut step: impl FnMut(T) -> T) -> Cycle {
+    let Ok(cycle) = try_floyd(start, |x| Ok::<T, Infallible>(step(x)));
+    cycle
+}
+
+/// [`floyd`] with a step that can fail, such as one that checks for cancellation
+pub fn try_floyd<T: Copy + PartialEq, E>(start: T, mut step: impl FnMut(T) -> Result<T, E>) -> Result<Cycle, E> {
+    let mut tortoise = step(start)?;
+    let mut hare = step(tortoise)?;
+    while tortoise != hare {
+        tortoise = step(tortoise)?;
+        hare = step(hare)?;
+        hare = step(hare)?;
+    }
+
+    // The hare is now a multiple of the cycle length ahead, so walking both at the
+    // same speed, one from the start, makes them meet where the cycle begins
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(tortoise)?;
+        hare = step(hare)?;
+        cycle_start += 1;
+    }
+
+    let mut len = 1;
+    hare = step(tortoise)?;
+    while tortoise != hare {
+        hare = step(hare)?;
+        len += 1;
+    }
+
+    Ok(Cycle { start: cycle_start, len })
+}
+
+/// Brent's cycle detection on the walk from `start`, which takes fewer steps than [`floyd`]
+pub fn brent<T: Copy + PartialEq>(start: T, mut step: impl FnMut(T) -> T) -> Cycle {
+    let Ok(cycle) = try_brent(start, |x| Ok::<T, Infallible>(step(x)));
+    cycle
+}
+
+/// [`brent`] with a step that can fail, such as one that checks for cancellation
+pub fn try_brent<T: Copy + PartialEq, E>(start: T, mut step: impl FnMut(T) -> Result<T, E>) -> Result<Cycle, E> {
+    // Find the cycle length by teleporting the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = start;
+    let mut hare = step(start)?;
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare;
+            power *= 2;
+            len = 0;
+        }
+        hare = step(hare)?;
+        len += 1;
+    }
+
+    // Then walk two pointers `len` apart from the start until they meet
+    let mut tortoise = start;
+    let mut hare = start;
+    for _ in 0..len {
+        hare = step(hare)?;
+    }
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = step(tortoise)?;
+        hare = step(hare)?;
+        cycle_start += 1;
+    }
+
+    Ok(Cycle { start: cycle_start, len })
+}
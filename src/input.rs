@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoadOptions {
+    pub trim_trailing_whitespace: bool,
+}
+
+/// Iterator over the lines of a byte buffer, without their `\n` terminators
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: &'a [u8],
+}
+
+// -------------------------------------------------------------------------- //
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions { trim_trailing_whitespace: true }
+    }
+}
+
+fn is_clean(bytes: &[u8], options: LoadOptions) -> bool {
+    if bytes.contains(&b'\r') || bytes.last().is_some_and(|&b| b != b'\n') {
+        return false;
+    }
+    if bytes == b"\n" || bytes.ends_with(b"\n\n") {
+        return false;
+    }
+    !options.trim_trailing_whitespace
+        || lines(bytes).all(|line| line.last().is_none_or(|b| !b.is_ascii_whitespace()))
+}
+
+/// Strips a UTF-8 BOM, converts CRLF and lone CR line endings to LF, trims trailing
+/// whitespace off every line unless told not to, and drops trailing blank lines so that
+/// the text ends with a single newline.
+///
+/// Input that is already in that shape is borrowed rather than copied.
+pub fn normalise(bytes: &[u8], options: LoadOptions) -> Cow<'_, [u8]> {
+    let bytes = bytes.strip_prefix(BOM).unwrap_or(bytes);
+    if is_clean(bytes, options) {
+        return Cow::Borrowed(bytes);
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() + 1);
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let end = rest.iter().position(|&b| b == b'\n' || b == b'\r').unwrap_or(rest.len());
+        let line = &rest[..end];
+        out.extend_from_slice(if options.trim_trailing_whitespace { line.trim_ascii_end() } else { line });
+        out.push(b'\n');
+
+        rest = match &rest[end..] {
+            [b'\r', b'\n', tail @ ..] | [b'\r', tail @ ..] | [b'\n', tail @ ..] => tail,
+            tail => tail,
+        };
+    }
+
+    while out.ends_with(b"\n\n") {
+        out.pop();
+    }
+    if out == b"\n" {
+        out.clear();
+    }
+
+    Cow::Owned(out)
+}
+
+pub fn load_with<P: AsRef<Path>>(path: P, options: LoadOptions) -> io::Result<Vec<u8>> {
+    let raw = fs::read(path)?;
+    match normalise(&raw, options) {
+        Cow::Borrowed(clean) if clean.len() == raw.len() => Ok(raw),
+        normalised => Ok(normalised.into_owned()),
+    }
+}
+
+/// Reads a puzzle input with the default normalisation applied
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    load_with(path, LoadOptions::default())
+}
+
+// -------------------------------------------------------------------------- //
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (line, rest) = match self.rest.iter().position(|&b| b == b'\n') {
+            Some(i) => (&self.rest[..i], &self.rest[i + 1..]),
+            None => (self.rest, &self.rest[self.rest.len()..]),
+        };
+        self.rest = rest;
+        Some(line)
+    }
+}
+
+/// Splits normalised input into lines, like `str::lines` but over bytes
+pub fn lines(bytes: &[u8]) -> Lines<'_> {
+    Lines { rest: bytes }
+}
+
+/// Whitespace separated fields of a line, skipping empty ones
+pub fn fields(line: &[u8]) -> impl Iterator<Item = &[u8]> {
+    line.split(u8::is_ascii_whitespace).filter(|f| !f.is_empty())
+}
+
+/// Splits at the first occurrence of `sep`, like `str::split_once`
+pub fn split_once<'a>(bytes: &'a [u8], sep: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let i = bytes.windows(sep.len()).position(|w| w == sep)?;
+    Some((&bytes[..i], &bytes[i + sep.len()..]))
+}
+
+/// Parses an unsigned ASCII decimal, `None` on empty input, stray bytes or overflow
+pub fn parse_uint<T: TryFrom<u64>>(bytes: &[u8]) -> Option<T> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let mut n = 0u64;
+    for &b in bytes {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        n = n.checked_mul(10)?.checked_add((b - b'0') as u64)?;
+    }
+    T::try_from(n).ok()
+}
+
+/// Parses an optionally negative ASCII decimal
+pub fn parse_int<T: TryFrom<i64>>(bytes: &[u8]) -> Option<T> {
+    let n: i64 = match bytes.strip_prefix(b"-") {
+        Some(digits) => parse_uint::<u64>(digits).and_then(|n| 0i64.checked_sub_unsigned(n))?,
+        None => parse_uint::<u64>(bytes.strip_prefix(b"+").unwrap_or(bytes))?.try_into().ok()?,
+    };
+    T::try_from(n).ok()
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+
+/// A JSON value, for the few places that write JSON without pulling in a serialisation
+/// crate. Objects keep their keys in the order they were given.
+///
+/// Displays compactly with `{}`, and indented with `{:#}`, where arrays and objects
+/// holding nothing but scalars stay on one line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i128),
+    /// Written as `null` when not finite, which JSON has no numbers for
+    Float(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+// -------------------------------------------------------------------------- //
+
+impl Json {
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Json {
+        Json::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    pub fn array<T: Into<Json>>(items: impl IntoIterator<Item = T>) -> Json {
+        Json::Array(items.into_iter().map(Into::into).collect())
+    }
+
+    fn is_scalar(&self) -> bool {
+        !matches!(self, Json::Array(_) | Json::Object(_))
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, indent: Option<usize>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(n) => write!(f, "{}", n),
+            Json::Float(x) if x.is_finite() => write!(f, "{}", x),
+            Json::Float(_) => f.write_str("null"),
+            Json::Str(s) => write_string(f, s),
+            Json::Array(items) => {
+                let flat = items.iter().all(Json::is_scalar);
+                write_container(f, ('[', ']'), items, indent, flat, |f, item, indent| item.write(f, indent))
+            }
+            Json::Object(fields) => {
+                let flat = fields.iter().all(|(_, v)| v.is_scalar());
+                write_container(f, ('{', '}'), fields, indent, flat, |f, (key, value), indent| {
+                    write_string(f, key)?;
+                    f.write_str(if indent.is_some() { ": " } else { ":" })?;
+                    value.write(f, indent)
+                })
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Writes the items between `brackets`, one per line at `indent` unless compact or `flat`
+fn write_container<T>(
+    f: &mut fmt::Formatter<'_>,
+    brackets: (char, char),
+    items: &[T],
+    indent: Option<usize>,
+    flat: bool,
+    mut write_item: impl FnMut(&mut fmt::Formatter<'_>, &T, Option<usize>) -> fmt::Result,
+) -> fmt::Result {
+    write!(f, "{}", brackets.0)?;
+    match indent {
+        Some(depth) if !flat && !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                let separator = if i == 0 { "" } else { "," };
+                write!(f, "{}\n{:width$}", separator, "", width = (depth + 1) * 2)?;
+                write_item(f, item, Some(depth + 1))?;
+            }
+            write!(f, "\n{:width$}", "", width = depth * 2)?;
+        }
+        _ => {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(if indent.is_some() { ", " } else { "," })?;
+                }
+                write_item(f, item, indent)?;
+            }
+        }
+    }
+    write!(f, "{}", brackets.1)
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, f.alternate().then_some(0))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Json {
+            fn from(n: $t) -> Json {
+                Json::Int(n as i128)
+            }
+        })*
+    };
+}
+
+from_int!(i32, i64, i128, u8, u16, u32, u64, usize);
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Json {
+        Json::Bool(b)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(x: f64) -> Json {
+        Json::Float(x)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Json {
+        Json::Str(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Json {
+        Json::Str(s)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Json {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(items: Vec<T>) -> Json {
+        Json::array(items)
+    }
+}
+
+/// Pairs such as coordinates become two element arrays
+impl<A: Into<Json>, B: Into<Json>> From<(A, B)> for Json {
+    fn from((a, b): (A, B)) -> Json {
+        Json::Array(vec![a.into(), b.into()])
+    }
+}
@@ -0,0 +1,34 @@
+pub mod args;
+pub mod bigint;
+pub mod cancel;
+pub mod checked;
+pub mod config;
+#[cfg(feature = "c-api")]
+pub mod ffi;
+pub mod graph;
+pub mod hash;
+pub mod input;
+pub mod json;
+pub mod rng;
+pub mod solution;
+
+pub mod year2023;
+
+use solution::Day;
+
+/// Every year with solutions, oldest first
+pub const YEARS: &[(u16, &[Day])] = &[
+    (2023, year2023::DAYS),
+];
+
+/// Year that commands address when none is given
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// All registered days, ordered by year then day
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|(_, days)| days.iter())
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    days().find(|d| d.year == year && d.day == day)
+}
@@ -0,0 +1,407 @@
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
+use std::panic;
+use std::process;
+use std::time::{Duration, Instant};
+use crate::args::{self, DayOption};
+use crate::bigint::BigInt;
+use crate::cancel::{Cancel, TimedOut};
+use crate::checked::Overflow;
+use crate::config::{Config, Param, Params};
+use crate::input::{self, LoadOptions};
+use crate::json::Json;
+use crate::rng::Rng;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Num(i128),
+    Big(BigInt),
+    TimedOut(String),
+    Overflow(Overflow),
+    Unsolved,
+}
+
+pub trait Solution {
+    type Model;
+
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Puzzle input baked into the binary with the `embed-inputs` feature
+    const INPUT: Option<&'static [u8]> = None;
+
+    /// Builds the model straight from the normalised input bytes
+    fn parse(input: &[u8], params: &Params) -> Self::Model;
+    fn part1(model: &Self::Model, cancel: &Cancel) -> Answer;
+    fn part2(model: &Self::Model, cancel: &Cancel) -> Answer;
+
+    /// Alternative algorithms to cross-check `part1` and `part2` against
+    fn strategies() -> Vec<Strategy<Self::Model>> {
+        Vec::new()
+    }
+
+    /// Optional text visualisation of the parsed model
+    fn render(_model: &Self::Model) -> Option<String> {
+        None
+    }
+
+    /// The parsed model as JSON, for looking at it with other tools
+    fn dump_model(_model: &Self::Model) -> Option<Json> {
+        None
+    }
+
+    /// Rewrites an input into a shareable one that keeps whatever structure the
+    /// solvers rely on, for days that know how to
+    fn anonymise(_input: &[u8], _rng: &mut Rng) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Whether line `index` has to stay when shrinking an input, so that it keeps its
+    /// structure. By default only the blank lines between blocks do.
+    fn pinned_line(_index: usize, line: &[u8]) -> bool {
+        line.is_empty()
+    }
+
+    /// Makes up a random input holding about `size` of whatever the day's input is a list
+    /// of, for days that know how to
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Properties of the input that the solvers take for granted
+    fn assumptions() -> Vec<Assumption> {
+        Vec::new()
+    }
+
+    /// Parameters the day reads, which `aoc.toml` is checked against when it is loaded
+    fn params() -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Command-line options of the day's binary, beyond the common ones
+    fn options() -> Vec<DayOption> {
+        Vec::new()
+    }
+}
+
+/// An input property some strategies rely on without checking it themselves
+pub struct Assumption {
+    pub name: &'static str,
+    /// Strategies that panic or give wrong answers when it does not hold, as (part, name)
+    pub relied_on_by: &'static [(u8, &'static str)],
+    pub check: fn(&[u8], &Params) -> Result<(), String>,
+}
+
+pub struct Strategy<M> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&M, &Cancel) -> Answer,
+}
+
+/// Options that apply to a whole run rather than a single day
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Time budget for each part, after which the solver is asked to stop
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    pub render: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyRun {
+    pub name: &'static str,
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Type-erased entry in the solution registry
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub embedded_input: Option<&'static [u8]>,
+    pub run: fn(&[u8], &Params, RunOptions) -> Run,
+    pub run_strategies: fn(&[u8], &Params, RunOptions) -> Vec<StrategyRun>,
+    pub run_part: fn(&[u8], &Params, u8, RunOptions) -> PartRun,
+    pub parse: fn(&[u8], &Params),
+    pub dump_model: fn(&[u8], &Params) -> Option<Json>,
+    pub anonymise: fn(&[u8], &mut Rng) -> Option<Vec<u8>>,
+    pub generate: fn(usize, &mut Rng) -> Option<Vec<u8>>,
+    pub pinned_line: fn(usize, &[u8]) -> bool,
+    pub assumptions: fn() -> Vec<Assumption>,
+    pub params: fn() -> Vec<Param>,
+}
+
+// -------------------------------------------------------------------------- //
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Num(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from!(i32, i64, u32, u64, usize);
+
+impl<T: Into<Answer>> From<Result<T, TimedOut>> for Answer {
+    fn from(result: Result<T, TimedOut>) -> Answer {
+        match result {
+            Ok(n) => n.into(),
+            Err(t) => Answer::TimedOut(t.progress),
+        }
+    }
+}
+
+impl<T: Into<Answer>> From<Result<T, Overflow>> for Answer {
+    fn from(result: Result<T, Overflow>) -> Answer {
+        match result {
+            Ok(n) => n.into(),
+            Err(o) => Answer::Overflow(o),
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    /// Keeps answers that fit in an `i128` as `Num` so that they compare equal
+    fn from(n: BigInt) -> Answer {
+        match n.to_i128() {
+            Some(small) => Answer::Num(small),
+            None => Answer::Big(n),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Num(n) => write!(f, "{}", n),
+            Self::Big(n) => write!(f, "{}", n),
+            Self::TimedOut(progress) => write!(f, "timed out ({})", progress),
+            Self::Overflow(o) => write!(f, "overflow ({})", o),
+            Self::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            embedded_input: S::INPUT,
+            run: run::<S>,
+            run_strategies: run_strategies::<S>,
+            run_part: run_part::<S>,
+            parse: parse_only::<S>,
+            dump_model: dump_model::<S>,
+            anonymise: S::anonymise,
+            generate: S::generate,
+            pinned_line: S::pinned_line,
+            assumptions: S::assumptions,
+            params: S::params,
+        }
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("src/year{}/day{}/input.txt", self.year, self.day)
+    }
+
+    /// The embedded input if there is one, otherwise the one at `input_path`
+    pub fn default_input(&self) -> Result<Cow<'static, [u8]>, String> {
+        match self.embedded_input {
+            Some(bytes) => Ok(input::normalise(bytes, LoadOptions::default())),
+            None => {
+                let path = self.input_path();
+                input::load(&path).map(Cow::Owned).map_err(|e| format!("cannot read {}: {}", path, e))
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+pub fn millis(d: Duration) -> f64 {
+    d.as_nanos() as f64 / 1e6
+}
+
+fn timed<T, F: FnOnce() -> T>(func: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = func();
+    (result, Instant::now() - start)
+}
+
+pub fn run<S: Solution>(input: &[u8], params: &Params, options: RunOptions) -> Run {
+    let (model, parse) = timed(|| S::parse(input, params));
+    let (part1, p1_time) = timed(|| S::part1(&model, &Cancel::after(options.timeout)));
+    let (part2, p2_time) = timed(|| S::part2(&model, &Cancel::after(options.timeout)));
+
+    Run {
+        part1,
+        part2,
+        timings: Timings { parse, part1: p1_time, part2: p2_time },
+        render: S::render(&model),
+    }
+}
+
+/// Parses the input and solves only one part, which must be 1 or 2
+pub fn run_part<S: Solution>(input: &[u8], params: &Params, part: u8, options: RunOptions) -> PartRun {
+    let (model, parse) = timed(|| S::parse(input, params));
+    let cancel = Cancel::after(options.timeout);
+    let (answer, solve) = match part {
+        1 => timed(|| S::part1(&model, &cancel)),
+        2 => timed(|| S::part2(&model, &cancel)),
+        _ => panic!("no part {}", part),
+    };
+
+    PartRun { answer, parse, solve }
+}
+
+/// Parses the input and throws the model away, to check that it is well-formed
+pub fn parse_only<S: Solution>(input: &[u8], params: &Params) {
+    S::parse(input, params);
+}
+
+/// Parses the input and returns the model as JSON, if the day knows how to write it
+pub fn dump_model<S: Solution>(input: &[u8], params: &Params) -> Option<Json> {
+    S::dump_model(&S::parse(input, params))
+}
+
+/// Runs the default solution of both parts followed by every alternative strategy
+pub fn run_strategies<S: Solution>(input: &[u8], params: &Params, options: RunOptions) -> Vec<StrategyRun> {
+    let model = S::parse(input, params);
+
+    let mut strategies = vec![
+        Strategy { name: "default", part: 1, solve: S::part1 },
+        Strategy { name: "default", part: 2, solve: S::part2 },
+    ];
+    strategies.extend(S::strategies());
+    strategies.sort_by_key(|s| s.part);
+
+    strategies.iter().map(|s| {
+        let cancel = Cancel::after(options.timeout);
+        let (answer, time) = timed(|| (s.solve)(&model, &cancel));
+        StrategyRun { name: s.name, part: s.part, answer, time }
+    }).collect()
+}
+
+/// Runs `func`, turning a panic into an error holding the panic message. The panic
+/// hook is left alone, so the message is still printed to stderr as usual.
+pub fn catch_panic<T, F: FnOnce() -> T>(func: F) -> Result<T, String> {
+    panic::catch_unwind(panic::AssertUnwindSafe(func)).map_err(|payload| {
+        if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            String::from("unknown panic")
+        }
+    })
+}
+
+pub fn print_run(run: &Run) {
+    println!("Part 1 result: {}", run.part1);
+    println!("Part 2 result: {}", run.part2);
+    println!("Completed in {} ms", millis(run.timings.total()));
+}
+
+fn exit_with(program: &str, message: &str) -> ! {
+    eprintln!("{}: {}", program, message);
+    eprintln!("try '{} --help'", program);
+    process::exit(2);
+}
+
+/// Shared entry point for the per-day binaries
+pub fn main<S: Solution>() {
+    let program = env::args().next().unwrap_or_else(|| format!("day{}", S::DAY));
+    let options = S::options();
+    let args = match args::parse(&env::args().skip(1).collect::<Vec<_>>(), &options) {
+        Ok(args) => args,
+        Err(e) => exit_with(&program, &e),
+    };
+    if args.help {
+        print!("{}", args::usage(&program, &options));
+        return;
+    }
+
+    let config = Config::load().unwrap_or_else(|e| exit_with(&program, &e));
+    let (input, path) = match (&args.input, S::INPUT) {
+        (Some(path), _) => match input::load(path) {
+            Ok(bytes) => (Cow::Owned(bytes), path.as_str()),
+            Err(e) => exit_with(&program, &format!("cannot read {}: {}", path, e)),
+        },
+        (None, Some(embedded)) => (input::normalise(embedded, LoadOptions::default()), "input.txt"),
+        (None, None) => exit_with(&program, "no input file given (or build with --features embed-inputs)"),
+    };
+
+    let mut params = config.params(S::YEAR, S::DAY, path);
+    for (param, value) in args.overrides {
+        params.set(param, value);
+    }
+    let run_options = RunOptions { timeout: args.timeout };
+
+    if args.dump_model {
+        match dump_model::<S>(&input, &params) {
+            Some(json) => println!("{:#}", json),
+            None => exit_with(&program, &format!("day {} has no JSON form of its model", S::DAY)),
+        }
+        return;
+    }
+
+    match args.part {
+        Some(part) => {
+            let run = run_part::<S>(&input, &params, part, run_options);
+            println!("Part {} result: {}", part, run.answer);
+            if args.verbose {
+                println!("Parsed in {} ms, solved in {} ms", millis(run.parse), millis(run.solve));
+            }
+            println!("Completed in {} ms", millis(run.parse + run.solve));
+        }
+        None => {
+            let run = run::<S>(&input, &params, run_options);
+            if args.verbose {
+                if let Some(render) = &run.render {
+                    println!("{}", render);
+                }
+            }
+            println!("Part 1 result: {}", run.part1);
+            println!("Part 2 result: {}", run.part2);
+            if args.verbose {
+                let t = run.timings;
+                println!("Parsed in {} ms, part 1 in {} ms, part 2 in {} ms", millis(t.parse), millis(t.part1), millis(t.part2));
+            }
+            println!("Completed in {} ms", millis(run.timings.total()));
+        }
+    }
+}
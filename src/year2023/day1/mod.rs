@@ -0,0 +1,91 @@
+use crate::cancel::Cancel;
+use crate::config::Params;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+
+/// Calibration totals of both parts, worked out together in a single pass while parsing,
+/// so the parse time covers both. A total is the number of the first line it found no
+/// digit on instead, which the part reports.
+pub struct Calibration {
+    /// Part 1, reading only the digits
+    digits: Result<u64, usize>,
+    /// Part 2, also reading digits spelled out as words
+    spelled: Result<u64, usize>,
+}
+
+pub struct Day1;
+
+const WORDS: [&[u8]; 9] = [b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine"];
+
+// -------------------------------------------------------------------------- //
+
+/// Adds the two-digit value from the first and last digit found on line `line_no` to
+/// `total`, unless there were none
+fn add_value(total: &mut Result<u64, usize>, line_no: usize, first: Option<u8>, last: Option<u8>) {
+    match (total.as_mut(), first, last) {
+        (Ok(sum), Some(first), Some(last)) => *sum += (first * 10 + last) as u64,
+        (Ok(_), _, _) => *total = Err(line_no),
+        (Err(_), _, _) => {}
+    }
+}
+
+fn calibration_totals(input: &[u8]) -> Calibration {
+    let mut totals = Calibration { digits: Ok(0), spelled: Ok(0) };
+
+    for (y, line) in input::lines(input).enumerate() {
+        let (mut first_digit, mut last_digit): (Option<u8>, Option<u8>) = (None, None);
+        let (mut first_spelled, mut last_spelled): (Option<u8>, Option<u8>) = (None, None);
+
+        for (i, &byte) in line.iter().enumerate() {
+            let substr = &line[i..];
+
+            let digit = byte.is_ascii_digit().then_some(byte - b'0');
+            let spelled = digit.or_else(|| {
+                WORDS.iter().position(|word| substr.starts_with(word)).map(|w| w as u8 + 1)
+            });
+
+            if digit.is_some() {
+                first_digit = first_digit.or(digit);
+                last_digit = digit;
+            }
+            if spelled.is_some() {
+                first_spelled = first_spelled.or(spelled);
+                last_spelled = spelled;
+            }
+        }
+
+        add_value(&mut totals.digits, y + 1, first_digit, last_digit);
+        add_value(&mut totals.spelled, y + 1, first_spelled, last_spelled);
+    }
+
+    totals
+}
+
+impl Solution for Day1 {
+    type Model = Calibration;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(input: &[u8], _params: &Params) -> Calibration {
+        calibration_totals(input)
+    }
+
+    fn part1(totals: &Calibration, _cancel: &Cancel) -> Answer {
+        match totals.digits {
+            Ok(total) => total.into(),
+            Err(line_no) => panic!("line {} has no digits", line_no),
+        }
+    }
+
+    fn part2(totals: &Calibration, _cancel: &Cancel) -> Answer {
+        match totals.spelled {
+            Ok(total) => total.into(),
+            Err(line_no) => panic!("line {} has no digits, spelled out or not", line_no),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use std::fmt;
+use crate::cancel::Cancel;
+use crate::config::Params;
+use crate::graph::Graph;
+use crate::hash::FxHashSet;
+use crate::input;
+use crate::json::Json;
+use crate::solution::{Answer, Assumption, Solution, Strategy};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MazeTile {
+    Empty,
+    Start,
+    Vertical,
+    Horizontal,
+    BendNE,
+    BendNW,
+    BendSW,
+    BendSE,
+}
+
+#[derive(Debug)]
+pub struct PipeMaze {
+    tiles: Vec<Vec<MazeTile>>,
+    start: (i64, i64),
+    loop_coords: Vec<(i64, i64)>,
+    actual_start_tile: MazeTile,
+    /// Tiles of the loop as `y * width + x`, with edges both ways between neighbours on it
+    pipe_graph: Graph,
+}
+
+pub struct Day10;
+
+// -------------------------------------------------------------------------- //
+
+impl Direction {
+    fn values() -> &'static [Direction] {
+        &[Self::North, Self::East, Self::South, Self::West]
+    }
+
+    fn offset_by(&self, xy: (i64, i64), by: i64) -> (i64, i64) {
+        match *self {
+            Self::North => (xy.0, xy.1 - by),
+            Self::East => (xy.0 + by, xy.1),
+            Self::South => (xy.0, xy.1 + by),
+            Self::West => (xy.0 - by, xy.1),
+        }
+    }
+
+    /// Direction of the step from `a` to the adjacent `b`
+    fn between(a: (i64, i64), b: (i64, i64)) -> Direction {
+        *Self::values().iter().find(|d| d.offset_by(a, 1) == b).expect("tiles are adjacent")
+    }
+
+    fn opposite(&self) -> Direction {
+        match *self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+}
+
+impl MazeTile {
+    fn new(from: u8) -> MazeTile {
+        match from {
+            b'.' => Self::Empty,
+            b'S' => Self::Start,
+            b'|' => Self::Vertical,
+            b'-' => Self::Horizontal,
+            b'L' => Self::BendNE,
+            b'J' => Self::BendNW,
+            b'7' => Self::BendSW,
+            b'F' => Self::BendSE,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Whether the tile has an opening on its `dir` side, which `S` has on every side
+    fn opens(&self, dir: Direction) -> bool {
+        *self == Self::Start || self.next_dir(dir.opposite()).is_some()
+    }
+
+    fn pipe_values() -> &'static [MazeTile] {
+        &[  Self::Vertical, Self::Horizontal,
+            Self::BendNE, Self::BendNW,
+            Self::BendSW, Self::BendSE  ]
+    }
+
+    fn next_dir(&self, indir: Direction) -> Option<Direction> {
+        match *self {
+            Self::Vertical => match indir {
+                Direction::North | Direction::South => Some(indir),
+                _ => None,
+            },
+            Self::Horizontal => match indir {
+                Direction::East | Direction::West => Some(indir),
+                _ => None,
+            },
+            Self::BendNE => match indir {
+                Direction::South => Some(Direction::East),
+                Direction::West => Some(Direction::North),
+                _ => None,
+            },
+            Self::BendNW => match indir {
+                Direction::South => Some(Direction::West),
+                Direction::East => Some(Direction::North),
+                _ => None,
+            },
+            Self::BendSW => match indir {
+                Direction::North => Some(Direction::West),
+                Direction::East => Some(Direction::South),
+                _ => None,
+            },
+            Self::BendSE => match indir {
+                Direction::North => Some(Direction::East),
+                Direction::West => Some(Direction::South),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MazeTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match *self { 
+            Self::Empty => " ",
+            Self::Start => "S",
+            // Unicode box-drawing characters for prettier pipes,
+            Self::Vertical => "│",
+            Self::Horizontal => "─",
+            Self::BendNE => "└",
+            Self::BendNW => "┘",
+            Self::BendSW => "┐",
+            Self::BendSE => "┌",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl PipeMaze {
+    fn new(tiles: Vec<Vec<MazeTile>>) -> PipeMaze {
+        let mut start: Option<(i64, i64)> = None;
+
+        for (y, line) in tiles.iter().enumerate() {
+            for (x, tile) in line.iter().enumerate() {
+                if *tile == MazeTile::Start {
+                    assert!(start.is_none());
+                    start = Some((x as i64, y as i64 ));
+                }
+            }
+        }
+
+        PipeMaze {
+            tiles,
+            start: start.unwrap(),
+            loop_coords: Vec::new(),
+            actual_start_tile: MazeTile::Start,
+            pipe_graph: Graph::default(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.tiles[0].len()
+    }
+
+    fn id(&self, xy: (i64, i64)) -> usize {
+        xy.1 as usize * self.width() + xy.0 as usize
+    }
+
+    fn coords(&self, id: usize) -> (i64, i64) {
+        ((id % self.width()) as i64, (id / self.width()) as i64)
+    }
+
+    fn at(&self, xy: (i64, i64)) -> Option<MazeTile> {
+        if (xy.0 < 0) || (xy.1 < 0) {
+            return None;
+        }
+        let x = xy.0 as usize;
+        let y = xy.1 as usize;
+
+        if y < self.tiles.len() {
+            let line = &self.tiles[y];
+            if x < line.len() {
+                return Some(line[x]);
+            }
+        }
+        None
+    }
+
+    fn adjacent(&self, xy: (i64, i64), dir: Direction) -> Option<MazeTile> {
+        self.at(dir.offset_by(xy, 1))
+    }
+
+    fn is_valid_dir(&self, xy: (i64, i64), dir: Direction) -> bool {
+        let a = self.adjacent(xy, dir);
+        match a {
+            Some(t) => t.next_dir(dir).is_some(),
+            None => false,
+        }
+    }
+
+    fn connects(&self, xy: (i64, i64), dir: Direction) -> bool {
+        match (self.at(xy), self.adjacent(xy, dir)) {
+            (Some(a), Some(b)) => a.opens(dir) && b.opens(dir.opposite()),
+            _ => false,
+        }
+    }
+
+    fn build_pipe_graph(&self) -> Graph {
+        let mut graph = Graph::new(self.tiles.len() * self.width());
+
+        for y in 0..self.tiles.len() as i64 {
+            for x in 0..self.width() as i64 {
+                for &d in Direction::values() {
+                    if self.connects((x, y), d) {
+                        graph.add_edge(self.id((x, y)), self.id(d.offset_by((x, y), 1)), ());
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    /// Tile ids of the loop from S round. Stray pipes may point into S as well, so
+    /// every pipe joined up with it is followed until one of them leads back.
+    fn find_loop(&self, graph: &Graph) -> Vec<usize> {
+        let start = self.id(self.start);
+
+        for first in graph.neighbours(start) {
+            let mut path = vec![start, first];
+            loop {
+                let (prev, node) = (path[path.len() - 2], path[path.len() - 1]);
+                // Every pipe but S joins up with at most two others
+                match graph.neighbours(node).find(|&n| n != prev) {
+                    Some(next) if next == start => return path,
+                    Some(next) => path.push(next),
+                    None => break,
+                }
+            }
+        }
+        panic!("no loop runs through S at {:?}", self.start);
+    }
+
+    fn extract_loop_tiles(&mut self) {
+        assert_eq!(self.at(self.start), Some(MazeTile::Start));
+        let ids = self.find_loop(&self.build_pipe_graph());
+
+        let mut graph = Graph::new(self.tiles.len() * self.width());
+        for (i, &a) in ids.iter().enumerate() {
+            let b = ids[(i + 1) % ids.len()];
+            graph.add_edge(a, b, ());
+            graph.add_edge(b, a, ());
+        }
+
+        let coords: Vec<(i64, i64)> = ids.into_iter().map(|id| self.coords(id)).collect();
+        let start_dir = Direction::between(coords[0], coords[1]);
+        let end_dir = Direction::between(*coords.last().unwrap(), coords[0]);
+
+        let coord_set: FxHashSet<(i64, i64)> = FxHashSet::from_iter(coords.clone());
+        for (y, line) in self.tiles.iter_mut().enumerate() {
+            for (x, tile) in line.iter_mut().enumerate() {
+                let c = (x as i64, y as i64);
+                if !coord_set.contains(&c) {
+                    *tile = MazeTile::Empty;
+                }
+            }
+        }
+
+        for t in MazeTile::pipe_values() {
+            let out_valid = t.next_dir(start_dir.opposite()).is_some();
+            let in_valid = t.next_dir(end_dir).is_some();
+
+            if out_valid && in_valid {
+                self.actual_start_tile = *t;
+            }
+        }
+        assert_ne!(self.actual_start_tile, MazeTile::Start);
+
+        self.loop_coords = coords;
+        self.pipe_graph = graph;
+    }
+}
+
+impl fmt::Display for PipeMaze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s: String = String::from("PipeMaze {\n");
+
+        // Fancy display with added border,
+        // NOTE: Requires terminal with codepage set to 65001 on Windows
+        s += "  ┌";
+        s += &"─".repeat(self.tiles[0].len());
+        s += "┐\n";
+    
+        for line in &self.tiles {
+            s += "  │";
+            for tile in line {
+                s += &format!("{}", tile);
+            }
+            s += "│\n";
+        }
+
+        s += "  └";
+        s += &"─".repeat(self.tiles[0].len());
+        s += "┘";
+    
+        write!(f, "{},\n}}", s)
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+/// Distance to the tile of the loop farthest from S, going round whichever way is shorter
+fn part1(pipes: &PipeMaze) -> usize {
+    pipes.pipe_graph.bfs(pipes.id(pipes.start)).into_iter().flatten().max().unwrap()
+}
+
+/// Shoelace formula for the loop area, then Pick's theorem for the interior points
+fn part2_shoelace(pipes: &PipeMaze) -> i64 {
+    let coords = &pipes.loop_coords;
+    let mut double_area = 0;
+
+    for (i, a) in coords.iter().enumerate() {
+        let b = coords[(i + 1) % coords.len()];
+        double_area += a.0 * b.1 - b.0 * a.1;
+    }
+
+    let area = double_area.abs() / 2;
+    area - (coords.len() as i64) / 2 + 1
+}
+
+fn part2(pipes: &PipeMaze) -> i64 {
+    let mut loop_min = pipes.loop_coords[0];
+    let mut loop_max = loop_min;
+
+    for c in &pipes.loop_coords {
+        loop_min.0 = loop_min.0.min(c.0);
+        loop_min.1 = loop_min.1.min(c.1);
+
+        loop_max.0 = loop_max.0.max(c.0);
+        loop_max.1 = loop_max.1.max(c.1);
+    }
+
+    let w = (loop_max.0 - loop_min.0 + 1) as usize;
+    let h = (loop_max.1 - loop_min.1 + 1) as usize;
+    let mut flags: Vec<Vec<u8>> = Vec::new();
+    flags.resize(h, vec![0; w]);
+
+    const TOP_EDGE: u8 = 0x1;
+    const BOTTOM_EDGE: u8 = 0x2;
+
+    for c in &pipes.loop_coords {
+        let x = (c.0 - loop_min.0) as usize;
+        let y = (c.1 - loop_min.1) as usize;
+        let t = if *c != pipes.start {
+            pipes.at(*c).unwrap()
+        } else {
+            pipes.actual_start_tile
+        };
+
+        flags[y][x] = match t {
+            MazeTile::Vertical => TOP_EDGE | BOTTOM_EDGE,
+            MazeTile::Horizontal => 0,
+            MazeTile::BendNE | MazeTile::BendNW => TOP_EDGE,
+            MazeTile::BendSE | MazeTile::BendSW => BOTTOM_EDGE,
+            _ => 0
+        };
+    }
+
+    let mut inner_count = 0;
+    for row in &flags {
+        let mut top_inside = false;
+        let mut bottom_inside = false;
+
+        for &f in row {
+            if (f & TOP_EDGE) != 0 {
+                top_inside = !top_inside;
+            }
+            if (f & BOTTOM_EDGE) != 0 {
+                bottom_inside = !bottom_inside;
+            }
+
+            if (f == 0) && top_inside && bottom_inside {
+                inner_count += 1;
+            }
+        }
+    }
+
+    inner_count
+}
+
+fn check_single_start(bytes: &[u8], _params: &Params) -> Result<(), String> {
+    match bytes.iter().filter(|&&b| b == b'S').count() {
+        1 => Ok(()),
+        n => Err(format!("found {} S tiles", n)),
+    }
+}
+
+/// Following one of the pipes that connect to `S` leads back to it, which
+/// `extract_loop_tiles` relies on to walk the loop and work out the start tile
+fn check_clean_loop(bytes: &[u8], params: &Params) -> Result<(), String> {
+    check_single_start(bytes, params)?;
+    let tiles: Vec<Vec<MazeTile>> = input::lines(bytes)
+        .map(|line| line.iter().copied().map(MazeTile::new).collect())
+        .collect();
+    let pipes = PipeMaze::new(tiles);
+
+    let exits: Vec<Direction> = Direction::values().iter().copied()
+        .filter(|&d| pipes.is_valid_dir(pipes.start, d)).collect();
+    if exits.len() < 2 {
+        return Err(format!("{} pipes connect to S at {:?}, expected at least 2", exits.len(), pipes.start));
+    }
+
+    let mut broken = Vec::new();
+    for &exit in &exits {
+        let mut pos = pipes.start;
+        let mut dir = exit;
+        loop {
+            pos = dir.offset_by(pos, 1);
+            dir = match pipes.at(pos) {
+                Some(MazeTile::Start) => return Ok(()),
+                Some(tile) => match tile.next_dir(dir) {
+                    Some(next) => next,
+                    None => {
+                        broken.push(format!("the pipe leaving S {:?} breaks at {:?}", exit, pos));
+                        break;
+                    }
+                },
+                None => {
+                    broken.push(format!("the pipe leaving S {:?} leaves the grid at {:?}", exit, pos));
+                    break;
+                }
+            };
+        }
+    }
+    Err(broken.join(", "))
+}
+
+impl Solution for Day10 {
+    type Model = PipeMaze;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> PipeMaze {
+        let mut tiles: Vec<Vec<MazeTile>> = Vec::new();
+
+        for line in input::lines(bytes) {
+            let l: Vec<MazeTile> = line.iter().copied().map(MazeTile::new).collect();
+            tiles.push(l);
+        }
+
+        let mut pipes = PipeMaze::new(tiles);
+        pipes.extract_loop_tiles();
+        pipes
+    }
+
+    fn part1(pipes: &PipeMaze, _cancel: &Cancel) -> Answer {
+        part1(pipes).into()
+    }
+
+    fn part2(pipes: &PipeMaze, _cancel: &Cancel) -> Answer {
+        part2(pipes).into()
+    }
+
+    fn strategies() -> Vec<Strategy<PipeMaze>> {
+        vec![
+            Strategy { name: "shoelace", part: 2, solve: |p, _| part2_shoelace(p).into() },
+        ]
+    }
+
+    fn render(pipes: &PipeMaze) -> Option<String> {
+        Some(pipes.to_string())
+    }
+
+    /// The loop from `S` round, as `[x, y]` pairs, along with the pipe `S` stands in for
+    fn dump_model(pipes: &PipeMaze) -> Option<Json> {
+        Some(Json::object([
+            ("width", Json::from(pipes.tiles.first().map_or(0, Vec::len))),
+            ("height", Json::from(pipes.tiles.len())),
+            ("start", Json::from(pipes.start)),
+            ("start_tile", Json::from(format!("{:?}", pipes.actual_start_tile))),
+            ("loop", Json::array(pipes.loop_coords.iter().copied())),
+        ]))
+    }
+
+    fn assumptions() -> Vec<Assumption> {
+        vec![
+            Assumption {
+                name: "there is exactly one S",
+                relied_on_by: &[(1, "default"), (2, "default"), (2, "shoelace")],
+                check: check_single_start,
+            },
+            Assumption {
+                name: "S sits on a single clean loop",
+                relied_on_by: &[(1, "default"), (2, "default"), (2, "shoelace")],
+                check: check_clean_loop,
+            },
+        ]
+    }
+}
@@ -0,0 +1,6 @@
+use advent_of_code::year2023::day11::Day11;
+use advent_of_code::solution;
+
+fn main() {
+    solution::main::<Day11>();
+}
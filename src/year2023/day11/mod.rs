@@ -0,0 +1,188 @@
+use crate::bigint::BigInt;
+use crate::cancel::Cancel;
+use crate::checked::{Arith, Overflow};
+use crate::args::{DayOption, Kind};
+use crate::config::{Param, Params};
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+
+#[derive(Debug)]
+struct GalaxyMap {
+    galaxies: Vec<(i64, i64)>,
+}
+
+pub struct ExpandedMaps {
+    part1: Result<GalaxyMap, Overflow>,
+    part2: Result<GalaxyMap, Overflow>,
+}
+
+pub struct Day11;
+
+const PART1_EXPANSION: i64 = 1;
+const PART2_EXPANSION: i64 = 999_999;
+
+const ARITH: Arith = Arith::new(Day11::YEAR, Day11::DAY);
+
+// -------------------------------------------------------------------------- //
+
+impl GalaxyMap {
+    fn new(map: &[&[u8]], empty_space_expansion: i64) -> Result<GalaxyMap, Overflow> {
+        let w = map[0].len();
+
+        let mut galaxies = vec![(0i64, 0i64); 0];
+        let mut empty_rows = vec![0usize; 0];
+        let mut empty_cols = vec![true; w];
+
+        for (y, row) in map.iter().enumerate() {
+            let mut row_empty = true;
+            for (x, tile) in row.iter().enumerate() {
+                if *tile == b'#' {
+                    galaxies.push((x as i64, y as i64));
+                    row_empty = false;
+                    empty_cols[x] = false;
+                }
+            }
+            if row_empty {
+                empty_rows.push(y);
+            }
+        }
+
+        for i in empty_rows {
+            for g in &mut galaxies {
+                if g.1 < (i as i64) {
+                    g.1 = ARITH.sub(g.1, empty_space_expansion, "row expansion")?;
+                }
+            }
+        }
+        for (i, e) in empty_cols.iter().enumerate() {
+            if *e {
+                for g in &mut galaxies {
+                    if g.0 < (i as i64) {
+                        g.0 = ARITH.sub(g.0, empty_space_expansion, "column expansion")?;
+                    }
+                }
+            }
+        }
+
+        Ok(GalaxyMap {
+            galaxies,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn checked_manhattan_dist(a: (i64, i64), b: (i64, i64)) -> Option<i64> {
+    let dx = b.0.checked_sub(a.0)?.checked_abs()?;
+    let dy = b.1.checked_sub(a.1)?.checked_abs()?;
+    dx.checked_add(dy)
+}
+
+fn checked_sum_galaxy_distances(map: &GalaxyMap) -> Option<i64> {
+    let mut sum = 0i64;
+
+    for i in 0..map.galaxies.len() {
+        let a = &map.galaxies[i];
+        for b in &map.galaxies[(i+1)..] {
+            let dist = checked_manhattan_dist(*a, *b)?;
+            sum = sum.checked_add(dist)?;
+        }
+    }
+
+    Some(sum)
+}
+
+fn sum_galaxy_distances(map: &Result<GalaxyMap, Overflow>) -> Answer {
+    let map = match map {
+        Ok(map) => map,
+        Err(overflow) => return Answer::Overflow(overflow.clone()),
+    };
+    if let Some(sum) = checked_sum_galaxy_distances(map) {
+        return sum.into();
+    }
+
+    let mut sum = BigInt::zero();
+    for i in 0..map.galaxies.len() {
+        let a = map.galaxies[i];
+        for b in &map.galaxies[(i+1)..] {
+            let dist = (b.0 as i128 - a.0 as i128).abs() + (b.1 as i128 - a.1 as i128).abs();
+            sum = sum + BigInt::from(dist);
+        }
+    }
+    sum.into()
+}
+
+/// `galaxies` galaxies scattered over a square with about four times as many cells,
+/// which leaves some rows and columns empty
+fn generate_image(galaxies: usize, rng: &mut Rng) -> Vec<u8> {
+    let side = ((galaxies * 4) as f64).sqrt().ceil().max(1.0) as usize;
+    let mut cells = vec![b'.'; side * side];
+    let mut placed = 0;
+
+    while placed < galaxies {
+        let cell = rng.below(cells.len() as u64) as usize;
+        if cells[cell] == b'.' {
+            cells[cell] = b'#';
+            placed += 1;
+        }
+    }
+
+    let mut out = Vec::with_capacity(side * (side + 1));
+    for row in cells.chunks(side) {
+        out.extend_from_slice(row);
+        out.push(b'\n');
+    }
+    out
+}
+
+impl Solution for Day11 {
+    type Model = ExpandedMaps;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], params: &Params) -> ExpandedMaps {
+        // Rows borrow straight from the input
+        let tiles: Vec<&[u8]> = input::lines(bytes).collect();
+
+        ExpandedMaps {
+            part1: GalaxyMap::new(&tiles, params.int("part1_expansion", PART1_EXPANSION)),
+            part2: GalaxyMap::new(&tiles, params.int("part2_expansion", PART2_EXPANSION)),
+        }
+    }
+
+    fn part1(maps: &ExpandedMaps, _cancel: &Cancel) -> Answer {
+        sum_galaxy_distances(&maps.part1)
+    }
+
+    fn part2(maps: &ExpandedMaps, _cancel: &Cancel) -> Answer {
+        sum_galaxy_distances(&maps.part2)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(generate_image(size, rng))
+    }
+
+    fn params() -> Vec<Param> {
+        vec![
+            Param { key: "part1_expansion", kind: Kind::Int },
+            Param { key: "part2_expansion", kind: Kind::Int },
+        ]
+    }
+
+    fn options() -> Vec<DayOption> {
+        vec![
+            DayOption {
+                name: "expansion",
+                param: "part2_expansion",
+                kind: Kind::Int,
+                help: "rows and columns added for every empty one in part 2 (999999)",
+            },
+        ]
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::Write;
+use crate::args::{DayOption, Kind};
+use crate::cancel::Cancel;
+use crate::checked::{Arith, Overflow};
+use crate::config::{Param, Params};
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Default)]
+struct Hand {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+#[derive(Debug)]
+struct Game {
+    id: i32,
+    hands: Vec<Hand>,
+}
+
+pub struct Record {
+    /// Or where adding up the cubes of a colour in some hand overflowed
+    games: Result<Vec<Game>, Overflow>,
+    max_hand: Hand,
+}
+
+pub struct Day2;
+
+const ARITH: Arith = Arith::new(Day2::YEAR, Day2::DAY);
+
+impl Hand {
+    fn greater(&self, other: &Hand) -> bool {
+        self.red > other.red ||
+        self.green > other.green ||
+        self.blue > other.blue
+    }
+
+    fn max(&mut self, other: &Hand) {
+        self.red = self.red.max(other.red);
+        self.green = self.green.max(other.green);
+        self.blue = self.blue.max(other.blue);
+    }
+
+    fn power(&self) -> Result<i32, Overflow> {
+        ARITH.mul(ARITH.mul(self.red, self.green, "red * green")?, self.blue, "red * green * blue")
+    }
+}
+
+fn parse_game(line: &[u8]) -> Result<Game, Overflow> {
+    let (game_header, game_content) = input::split_once(line, b": ").unwrap();
+
+    let game_id_str = game_header.strip_prefix(b"Game ").unwrap();
+    let game_id = input::parse_uint(game_id_str).unwrap();
+
+    let mut hands = Vec::new();
+
+    for cube_hand in game_content.split(|&b| b == b';') {
+        let mut current_hand = Hand { ..Default::default() };
+
+        for cube in cube_hand.split(|&b| b == b',') {
+            let (value, color) = {
+                let mut iter = input::fields(cube);
+                let n: i32 = input::parse_uint(iter.next().unwrap()).unwrap();
+                let c = iter.next().unwrap();
+                (n, c)
+            };
+            let count = match color {
+                b"red" => &mut current_hand.red,
+                b"green" => &mut current_hand.green,
+                b"blue" => &mut current_hand.blue,
+                _ => unreachable!()
+            };
+            *count = ARITH.add(*count, value, "cubes of a colour in a hand")?;
+        }
+
+        hands.push(current_hand);
+    }
+
+    Ok(Game { id: game_id, hands })
+}
+
+/// `games` games of one to six hands, each showing up to 20 cubes of some colours
+fn generate_games(games: usize, rng: &mut Rng) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    for id in 1..=games {
+        write!(out, "Game {}: ", id).unwrap();
+        for hand in 0..1 + rng.below(6) {
+            if hand > 0 {
+                out.extend_from_slice(b"; ");
+            }
+            let mut colours = [&b"red"[..], b"green", b"blue"];
+            rng.shuffle(&mut colours);
+            for (i, colour) in colours.iter().take(1 + rng.below(3) as usize).enumerate() {
+                if i > 0 {
+                    out.extend_from_slice(b", ");
+                }
+                write!(out, "{} ", 1 + rng.below(20)).unwrap();
+                out.extend_from_slice(colour);
+            }
+        }
+        out.push(b'\n');
+    }
+    out
+}
+
+impl Solution for Day2 {
+    type Model = Record;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], params: &Params) -> Record {
+        let limits = params.uints("max_hand", &[12, 13, 14]);
+        assert_eq!(limits.len(), 3, "max_hand must list red, green and blue");
+
+        // No hand can hold more cubes than an i32 does, so larger limits are the same as i32::MAX
+        let limit = |n: usize| i32::try_from(n).unwrap_or(i32::MAX);
+        let max_hand = Hand {
+            red: limit(limits[0]),
+            green: limit(limits[1]),
+            blue: limit(limits[2]),
+        };
+
+        Record {
+            games: input::lines(bytes).map(parse_game).collect(),
+            max_hand,
+        }
+    }
+
+    fn part1(record: &Record, _cancel: &Cancel) -> Answer {
+        let games = match &record.games {
+            Ok(games) => games,
+            Err(overflow) => return Answer::Overflow(overflow.clone()),
+        };
+        let mut valid_games = games.iter().filter(|game| {
+            !game.hands.iter().any(|h| h.greater(&record.max_hand))
+        });
+        valid_games.try_fold(0, |sum, game| ARITH.add(sum, game.id, "sum of game ids")).into()
+    }
+
+    fn part2(record: &Record, _cancel: &Cancel) -> Answer {
+        let games = match &record.games {
+            Ok(games) => games,
+            Err(overflow) => return Answer::Overflow(overflow.clone()),
+        };
+        let total_power = games.iter().try_fold(0, |total, game| {
+            let mut required_hand = Hand { ..Default::default() };
+            for hand in &game.hands {
+                required_hand.max(hand);
+            }
+            ARITH.add(total, required_hand.power()?, "total power")
+        });
+
+        total_power.into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(generate_games(size, rng))
+    }
+
+    fn params() -> Vec<Param> {
+        vec![
+            Param { key: "max_hand", kind: Kind::Uints(3) },
+        ]
+    }
+
+    fn options() -> Vec<DayOption> {
+        vec![
+            DayOption {
+                name: "limits",
+                param: "max_hand",
+                kind: Kind::Uints(3),
+                help: "red, green and blue cubes in the bag for part 1 (12,13,14)",
+            },
+        ]
+    }
+}
@@ -0,0 +1,6 @@
+use advent_of_code::year2023::day3::Day3;
+use advent_of_code::solution;
+
+fn main() {
+    solution::main::<Day3>();
+}
@@ -0,0 +1,286 @@
+use std::fmt;
+use std::str;
+use crate::args::{DayOption, Kind};
+use crate::cancel::Cancel;
+use crate::config::{Param, Params};
+use crate::hash::FxHashSet;
+use crate::input;
+use crate::solution::{Answer, Assumption, Solution};
+
+struct Rect {
+    top: usize,
+    bottom: usize,
+    left: usize,
+    right: usize,
+}
+
+impl Rect {
+    fn grow(&mut self, by: usize, bounds: (usize, usize)) {
+        self.right = {
+            let r = self.right + by;
+            if r < bounds.0 { r } else { bounds.0 }
+        };
+        self.bottom = {
+            let b = self.bottom + by;
+            if b < bounds.1 { b } else { bounds.1 }
+        };
+        self.top = self.top.saturating_sub(by);
+        self.left = self.left.saturating_sub(by);
+    }
+}
+
+/// Row-major grid stored in a single contiguous buffer
+#[derive(Debug)]
+struct ByteMatrix {
+    bytes: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+pub struct Schematic {
+    matrix: ByteMatrix,
+    num_ranges: Vec<Vec<(usize, usize)>>,
+    /// Characters that make the numbers next to them part numbers
+    symbols: Vec<u8>,
+}
+
+/// Every ASCII punctuation character but the `.` of empty cells
+const SYMBOLS: &str = "!\"#$%&'()*+,-/:;<=>?@[\\]^_`{|}~";
+
+pub struct Day3;
+
+impl ByteMatrix {
+    fn from_lines(input: &[u8], width: usize, height: usize) -> ByteMatrix {
+        let mut bytes = Vec::with_capacity(width * height);
+
+        for (y, line) in input::lines(input).enumerate() {
+            assert_eq!(line.len(), width, "line {} of the schematic is not {} wide", y + 1, width);
+            bytes.extend_from_slice(line);
+        }
+        assert_eq!(bytes.len(), width * height, "the schematic is not {} lines tall", height);
+
+        ByteMatrix { bytes, width, height }
+    }
+
+    fn width(&self) -> usize { self.width }
+
+    fn height(&self) -> usize { self.height }
+
+    fn at(&self, x: usize, y: usize) -> u8 { self.bytes[y * self.width + x] }
+
+    fn row(&self, row: usize) -> &[u8] { &self.bytes[row * self.width..(row + 1) * self.width] }
+
+    fn parse_num_at(&self, x: usize, y: usize) -> Option<u32> {
+        let row = self.row(y);
+        let start = x;
+
+        if !row[start].is_ascii_digit() {
+            return None;
+        }
+        let mut iter = row[start..].iter();
+        let end = {
+            let pos = iter.position(|c| !c.is_ascii_digit());
+            match pos {
+                Some(p) => start + p,
+                None => self.width(),
+            }
+        };
+        input::parse_uint(&row[start..end])
+    }
+
+    fn for_each_in_rect<F>(&self, r: &Rect, mut func: F)
+        where F: FnMut(u8, usize, usize),
+    {
+        for y in r.top..r.bottom {
+            for x in r.left..r.right {
+                func(self.at(x, y), x, y)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ByteMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.bytes.chunks(self.width) {
+            writeln!(f, "{}", str::from_utf8(row).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn part1(matrix: &ByteMatrix, num_ranges: &[Vec<(usize, usize)>], symbols: &[u8]) -> u32 {
+    let width = matrix.width();
+    let height = matrix.height();
+    let mut sum = 0u32;
+
+    for (y, row_nums) in num_ranges.iter().enumerate() {
+        for (start, end) in row_nums {
+            let rect = {
+                let mut r = Rect {
+                    top: y, bottom: y + 1, left: *start, right: *end + 1 
+                };
+                r.grow(1, (width, height));
+                r
+            };
+
+            let mut valid_part_num = false;
+            matrix.for_each_in_rect(&rect, |c, _, _| {
+                if symbols.contains(&c) {
+                    valid_part_num = true;
+                }
+            });
+            if valid_part_num {
+                let n = matrix.parse_num_at(*start, y).unwrap();
+                sum += n;
+            }
+        }
+    }
+    sum
+}
+
+fn part2(matrix: &ByteMatrix, num_ranges: &[Vec<(usize, usize)>]) -> u32 {
+    let width = matrix.width();
+    let height = matrix.height();
+    let mut sum = 0u32;
+
+    for y in 0..height {
+        let row = matrix.row(y);
+
+        for (x, &c) in row.iter().enumerate() {
+            let rect = {
+                let mut r = Rect {
+                    top: y, bottom: y + 1, left: x, right: x + 1
+                };
+                r.grow(1, (width, height));
+                r
+            };
+            if c == b'*' {
+                let mut overlapping: FxHashSet<(usize, usize)> = FxHashSet::default();
+                matrix.for_each_in_rect(&rect, |_, x, y| {
+                    let mut iter = num_ranges[y].iter();
+                    let contained = iter.find(
+                        |(s, e)| x >= *s && x <= *e
+                    );
+                    if let Some((s, _)) = contained {
+                        overlapping.insert((*s, y));
+                    }
+                });
+
+                if overlapping.len() != 2 {
+                    continue;
+                }
+
+                let mut n = 1u32;
+                for (x, y) in overlapping {
+                    n *= matrix.parse_num_at(x, y).unwrap();
+                }
+
+                sum += n;
+            }
+        }
+    }
+    sum
+}
+
+/// The matrix is sized up front from the `width` and `height` parameters
+fn check_grid_size(bytes: &[u8], params: &Params) -> Result<(), String> {
+    let width = params.uint("width", 140);
+    let height = params.uint("height", 140);
+
+    let mut rows = 0;
+    for (y, line) in input::lines(bytes).enumerate() {
+        if line.len() != width {
+            return Err(format!("line {} is {} wide, expected {}", y + 1, line.len(), width));
+        }
+        rows += 1;
+    }
+    if rows != height {
+        return Err(format!("{} lines, expected {}", rows, height));
+    }
+    Ok(())
+}
+
+impl Solution for Day3 {
+    type Model = Schematic;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], params: &Params) -> Schematic {
+        let width = params.uint("width", 140);
+        let height = params.uint("height", 140);
+        let matrix = ByteMatrix::from_lines(bytes, width, height);
+
+        let mut num_ranges: Vec<Vec<(usize, usize)>> = Vec::new();
+        num_ranges.resize(height, Default::default());
+
+        for (y, row_nums) in num_ranges.iter_mut().enumerate() {
+            let row = matrix.row(y);
+            let mut in_number = false;
+
+            for (i, c) in row.iter().enumerate() {
+                if c.is_ascii_digit() {
+                    if in_number {
+                        row_nums.last_mut().unwrap().1 = i;
+                    }
+                    if !in_number {
+                        in_number = true;
+                        row_nums.push((i, i));
+                    }
+                } else {
+                    in_number = false;
+                }
+            }
+        }
+
+        let symbols = params.string("symbols", SYMBOLS).into_bytes();
+
+        Schematic { matrix, num_ranges, symbols }
+    }
+
+    fn part1(schematic: &Schematic, _cancel: &Cancel) -> Answer {
+        part1(&schematic.matrix, &schematic.num_ranges, &schematic.symbols).into()
+    }
+
+    fn part2(schematic: &Schematic, _cancel: &Cancel) -> Answer {
+        part2(&schematic.matrix, &schematic.num_ranges).into()
+    }
+
+    fn render(schematic: &Schematic) -> Option<String> {
+        Some(schematic.matrix.to_string())
+    }
+
+    fn params() -> Vec<Param> {
+        vec![
+            Param { key: "width", kind: Kind::Uint },
+            Param { key: "height", kind: Kind::Uint },
+            Param { key: "symbols", kind: Kind::Str },
+        ]
+    }
+
+    fn options() -> Vec<DayOption> {
+        vec![
+            DayOption {
+                name: "symbols",
+                param: "symbols",
+                kind: Kind::Str,
+                help: "characters that mark part numbers in part 1 (all punctuation but '.')",
+            },
+        ]
+    }
+
+    fn assumptions() -> Vec<Assumption> {
+        vec![
+            Assumption {
+                name: "grid is width x height (140x140 unless configured)",
+                relied_on_by: &[(1, "default"), (2, "default")],
+                check: check_grid_size,
+            },
+        ]
+    }
+}
@@ -0,0 +1,185 @@
+use std::io::Write;
+use crate::bigint::BigInt;
+use crate::cancel::{Cancel, TimedOut};
+use crate::checked::{Arith, Overflow};
+use crate::config::Params;
+use crate::hash::FxHashSet;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution, Strategy};
+
+#[derive(Debug)]
+pub struct Card {
+    intersect_count: usize,
+}
+
+pub struct Day4;
+
+const ARITH: Arith = Arith::new(Day4::YEAR, Day4::DAY);
+
+impl Card {
+    fn new(winning: impl Iterator<Item = u32>, have: impl Iterator<Item = u32>) -> Card {
+        let win_set: FxHashSet<u32> = FxHashSet::from_iter(winning);
+        let have_set: FxHashSet<u32> = FxHashSet::from_iter(have);
+        let intersect = win_set.intersection(&have_set).count();
+
+        Card {
+            intersect_count: intersect,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn part1(cards: &[Card]) -> Result<usize, Overflow> {
+    let mut sum = 0;
+
+    for card in cards {
+        let win_count = card.intersect_count;
+        if win_count > 0 {
+            let bits = u32::try_from(win_count - 1).unwrap_or(u32::MAX);
+            let points = ARITH.shl(1, bits, "1 << (win_count - 1)")?;
+            sum = ARITH.add(sum, points, "sum of points")?;
+        }
+    }
+    Ok(sum)
+}
+
+/// The copy count equals the number of calls, so `cancel` is only polled every few thousand
+fn depth_first_count_copies(cards: &[Card], cancel: &Cancel, calls: &mut usize) -> Result<Result<usize, Overflow>, TimedOut> {
+    let mut count = 1;
+
+    *calls += 1;
+    if calls.is_multiple_of(4096) {
+        cancel.check(|| format!("{} copies counted", calls))?;
+    }
+
+    let card = &cards[0];
+    let win_count = card.intersect_count;
+
+    for i in 1..win_count+1 {
+        let copies = match depth_first_count_copies(&cards[i..], cancel, calls)? {
+            Ok(copies) => copies,
+            overflow => return Ok(overflow),
+        };
+        count = match ARITH.add(count, copies, "copies of a card") {
+            Ok(count) => count,
+            overflow => return Ok(overflow),
+        };
+    }
+
+    Ok(Ok(count))
+}
+
+fn part2(cards: &[Card], cancel: &Cancel) -> Result<Result<usize, Overflow>, TimedOut> {
+    let mut sum = 0;
+    let mut calls = 0;
+
+    for i in 0..cards.len() {
+        let copies = depth_first_count_copies(&cards[i..], cancel, &mut calls)
+            .map_err(|t| TimedOut { progress: format!("card {} of {}, {}", i + 1, cards.len(), t.progress) })?;
+        sum = match copies.and_then(|copies| ARITH.add(sum, copies, "sum of copies")) {
+            Ok(sum) => sum,
+            overflow => return Ok(overflow),
+        };
+    }
+
+    Ok(Ok(sum))
+}
+
+fn checked_part2_dp(cards: &[Card]) -> Option<usize> {
+    let mut copies = vec![1usize; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let won = card.intersect_count.min(cards.len() - i - 1);
+        for j in (i + 1)..(i + 1 + won) {
+            copies[j] = copies[j].checked_add(copies[i])?;
+        }
+    }
+
+    copies.iter().try_fold(0usize, |sum, c| sum.checked_add(*c))
+}
+
+/// Copy counts can grow exponentially, so fall back to big integers on overflow
+fn part2_dp(cards: &[Card]) -> Answer {
+    if let Some(sum) = checked_part2_dp(cards) {
+        return sum.into();
+    }
+
+    let mut copies = vec![BigInt::from(1); cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let won = card.intersect_count.min(cards.len() - i - 1);
+        for j in (i + 1)..(i + 1 + won) {
+            copies[j] = &copies[j] + &copies[i];
+        }
+    }
+
+    copies.iter().fold(BigInt::zero(), |sum, c| &sum + c).into()
+}
+
+fn parse_card(line: &[u8]) -> Card {
+    let (header, body) = input::split_once(line, b": ").unwrap();
+
+    assert!(header.starts_with(b"Card "));
+
+    let (winning, have) = input::split_once(body, b" | ").unwrap();
+    let parse_all = |nums| input::fields(nums).map(|n| input::parse_uint(n).unwrap());
+
+    Card::new(parse_all(winning), parse_all(have))
+}
+
+/// `cards` cards laid out like the real ones, 10 winning numbers and 25 numbers you
+/// have, each winning up to 4 copies so that the copy counts keep growing. Like in the
+/// puzzle, no card wins copies of cards past the end of the table.
+fn generate_cards(cards: usize, rng: &mut Rng) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    for id in 1..=cards {
+        let mut numbers: Vec<u64> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let wins = (rng.below(5) as usize).min(cards - id);
+
+        let (winning, rest) = numbers.split_at(10);
+        let mut have: Vec<u64> = winning[..wins].iter().chain(&rest[..25 - wins]).copied().collect();
+        rng.shuffle(&mut have);
+
+        write!(out, "Card {:>3}:", id).unwrap();
+        winning.iter().for_each(|n| write!(out, " {:>2}", n).unwrap());
+        write!(out, " |").unwrap();
+        have.iter().for_each(|n| write!(out, " {:>2}", n).unwrap());
+        out.push(b'\n');
+    }
+    out
+}
+
+impl Solution for Day4 {
+    type Model = Vec<Card>;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> Vec<Card> {
+        input::lines(bytes).map(parse_card).collect()
+    }
+
+    fn part1(cards: &Vec<Card>, _cancel: &Cancel) -> Answer {
+        part1(cards).into()
+    }
+
+    fn part2(cards: &Vec<Card>, cancel: &Cancel) -> Answer {
+        part2(cards, cancel).into()
+    }
+
+    fn strategies() -> Vec<Strategy<Vec<Card>>> {
+        vec![
+            Strategy { name: "dp", part: 2, solve: |cards, _| part2_dp(cards) },
+        ]
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(generate_cards(size, rng))
+    }
+}
@@ -0,0 +1,395 @@
+use std::cmp::Ordering;
+use std::io::Write;
+use rayon::prelude::*;
+use crate::cancel::{Cancel, TimedOut};
+use crate::checked::{Arith, Overflow};
+use crate::config::Params;
+use crate::input::{self, Lines};
+use crate::json::Json;
+use crate::rng::Rng;
+use crate::solution::{Answer, Assumption, Solution, Strategy};
+
+
+#[derive(Debug, Copy, Clone)]
+struct RangeMap {
+    dst: usize,
+    src: usize,
+    len: usize,
+}
+
+#[derive(Clone)]
+struct MultiRangeMap {
+    ranges: Vec<RangeMap>
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    seed_mapping_stack: Vec<MultiRangeMap>,
+}
+
+pub struct Day5;
+
+/// The maps in the order the almanac lists them, which is also the order they apply in
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+const ARITH: Arith = Arith::new(Day5::YEAR, Day5::DAY);
+
+// -------------------------------------------------------------------------- //
+
+impl RangeMap {
+    /// Compares the offset rather than computing `src + len`, which cannot overflow
+    fn in_src_range(&self, index: usize) -> bool {
+        index >= self.src && index - self.src < self.len
+    }
+
+    fn map(&self, index: usize) -> Result<Option<usize>, Overflow> {
+        if self.in_src_range(index) {
+            let offset = index - self.src;
+            ARITH.add(self.dst, offset, "dst + offset").map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn to_json(self) -> Json {
+        Json::object([("dst", self.dst.into()), ("src", self.src.into()), ("len", self.len.into())])
+    }
+}
+
+impl MultiRangeMap {
+    fn new(ranges: &[RangeMap]) -> MultiRangeMap {
+        let mut srm = MultiRangeMap { ranges: ranges.into() };
+        srm.sort_by_src();
+        srm
+    }
+
+    fn sort_by_src(&mut self) {
+        self.ranges.sort_by(|a, b| {
+            a.src.cmp(&b.src)
+        });
+    }
+
+    fn find_pivot(&self, index: usize) -> Result<usize, usize> {
+        self.ranges.binary_search_by(|r| {
+            if r.in_src_range(index) {
+                Ordering::Equal
+            } else {
+                r.src.cmp(&index)
+            }
+        })
+    }
+
+    /// Maps half-open `[start, end)` intervals, splitting them at range boundaries
+    fn map_intervals(&self, intervals: &[(usize, usize)]) -> Result<Vec<(usize, usize)>, Overflow> {
+        let mut mapped = Vec::new();
+
+        for &(start, end) in intervals {
+            let mut cursor = start;
+
+            for r in &self.ranges {
+                let src_end = ARITH.add(r.src, r.len, "src + len")?;
+                if src_end <= cursor {
+                    continue;
+                }
+                if r.src >= end {
+                    break;
+                }
+                if r.src > cursor {
+                    mapped.push((cursor, r.src));
+                    cursor = r.src;
+                }
+
+                let split = end.min(src_end);
+                mapped.push((
+                    ARITH.add(r.dst, cursor - r.src, "dst + offset")?,
+                    ARITH.add(r.dst, split - r.src, "dst + offset")?,
+                ));
+                cursor = split;
+            }
+
+            if cursor < end {
+                mapped.push((cursor, end));
+            }
+        }
+
+        Ok(mapped)
+    }
+
+    fn map(&self, index: usize) -> Result<Option<usize>, Overflow> {
+        let p = self.find_pivot(index);
+        match p {
+            Ok(i) => self.ranges[i].map(index),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Almanac {
+    fn seed_to_location(&self, seed: usize) -> Result<usize, Overflow> {
+        let mut index = seed;
+        for m in &self.seed_mapping_stack {
+            index = match m.map(index)? {
+                Some(i) => i,
+                None => index,
+            };
+        }
+        Ok(index)
+    }
+
+    /// Seed ranges as half-open `[start, end)` intervals
+    fn seed_intervals(&self) -> Result<Vec<(usize, usize)>, Overflow> {
+        self.seeds.chunks(2)
+            .map(|c| Ok((c[0], ARITH.add(c[0], c[1], "seed start + len")?)))
+            .collect()
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn part1(almanac: &Almanac) -> Result<usize, Overflow> {
+    let mut minimum: usize = usize::MAX;
+    
+    for seed in &almanac.seeds {
+        let location = almanac.seed_to_location(*seed)?;
+        minimum = minimum.min(location);
+    }
+
+    Ok(minimum)
+}
+
+fn part2(almanac: &Almanac) -> Result<usize, Overflow> {
+    let mut intervals = almanac.seed_intervals()?;
+
+    for m in &almanac.seed_mapping_stack {
+        intervals = m.map_intervals(&intervals)?;
+    }
+
+    Ok(intervals.iter().map(|(start, _)| *start).min().unwrap())
+}
+
+fn part2_brute_force(almanac: &Almanac, cancel: &Cancel) -> Result<Result<usize, Overflow>, TimedOut> {
+    const CHUNK: usize = 1 << 22;
+
+    let ranges = match almanac.seed_intervals() {
+        Ok(ranges) => ranges,
+        Err(overflow) => return Ok(Err(overflow)),
+    };
+    let total: usize = ranges.iter().map(|(start, end)| end - start).sum();
+    let mut checked = 0;
+    let mut minimum = usize::MAX;
+
+    // Really dumb, parallel brute-force solution
+    for (start, end) in ranges {
+        for chunk_start in (start..end).step_by(CHUNK) {
+            cancel.check(|| format!("{} of {} seeds checked, lowest location so far {}",
+                checked, total, minimum))?;
+
+            let chunk_end = chunk_start.saturating_add(CHUNK).min(end);
+            let iter = (chunk_start..chunk_end).into_par_iter();
+            let locations = iter.map(|seed| almanac.seed_to_location(seed));
+            let min_loc = match locations.try_reduce(|| usize::MAX, |a, b| Ok(a.min(b))) {
+                Ok(location) => location,
+                Err(overflow) => return Ok(Err(overflow)),
+            };
+
+            minimum = minimum.min(min_loc);
+            checked += chunk_end - chunk_start;
+        }
+    }
+
+    Ok(Ok(minimum))
+}
+
+fn parse_mappings(lines: &mut Lines<'_>, expected_header: &[u8]) -> MultiRangeMap {
+    loop {
+        // Can't use skip_while since that moves away lines
+        let header = lines.next().unwrap();
+        if !header.is_empty() {
+            assert_eq!(header, expected_header);
+            break;
+        }
+    }
+
+    let mut ranges: Vec<RangeMap> = Vec::new();
+    for line in lines.take_while(|l| !l.is_empty()) {
+        let mut nums = input::fields(line).map(|n| input::parse_uint(n).unwrap());
+        let (dst, src, len) = (nums.next().unwrap(), nums.next().unwrap(), nums.next().unwrap());
+        assert!(nums.next().is_none());
+
+        ranges.push(RangeMap { dst, src, len });
+    }
+
+    MultiRangeMap::new(&ranges)
+}
+
+/// Moves each of the categories between seeds and locations by a random offset of its
+/// own and shuffles the ranges. To keep numbers the maps leave alone where they were
+/// relative to each other, every map first gets explicit ranges for the gaps below the
+/// largest number in the almanac. Seeds and locations stay put, so both answers do too.
+fn shift_ranges(bytes: &[u8], rng: &mut Rng) -> Vec<u8> {
+    let almanac = Day5::parse(bytes, &Params::default());
+    let end = |start: usize, len: usize| start.checked_add(len).expect("a range ends past usize::MAX");
+
+    let pairs = almanac.seeds.chunks(2).filter_map(|pair| match *pair {
+        [start, len] => Some(end(start, len)),
+        _ => None,
+    });
+    let ranges = almanac.seed_mapping_stack.iter().flat_map(|m| &m.ranges)
+        .flat_map(|r| [end(r.src, r.len), end(r.dst, r.len)]);
+    let limit = almanac.seeds.iter().copied().chain(pairs).chain(ranges).max().unwrap_or(0);
+
+    // Offsets of the categories, the seeds being the first and the locations the last
+    let mut offsets = vec![0; MAPS.len() + 1];
+    for offset in &mut offsets[1..MAPS.len()] {
+        *offset = rng.below(1_000_000_000) as usize;
+    }
+    let shift = |n: usize, offset: usize| n.checked_add(offset).expect("a shifted number is past usize::MAX");
+
+    let mut out = Vec::with_capacity(bytes.len() * 2);
+    let mut pairs: Vec<&[usize]> = almanac.seeds.chunks(2).collect();
+    rng.shuffle(&mut pairs);
+    write!(out, "seeds:").unwrap();
+    for n in pairs.concat() {
+        write!(out, " {}", n).unwrap();
+    }
+    writeln!(out).unwrap();
+
+    for (i, (name, map)) in MAPS.iter().zip(&almanac.seed_mapping_stack).enumerate() {
+        let mut ranges = map.ranges.clone();
+        let mut cursor = 0;
+        for r in &map.ranges {
+            if r.src > cursor {
+                ranges.push(RangeMap { dst: cursor, src: cursor, len: r.src - cursor });
+            }
+            cursor = cursor.max(end(r.src, r.len));
+        }
+        if limit > cursor {
+            ranges.push(RangeMap { dst: cursor, src: cursor, len: limit - cursor });
+        }
+        rng.shuffle(&mut ranges);
+
+        writeln!(out, "\n{} map:", name).unwrap();
+        for r in ranges {
+            writeln!(out, "{} {} {}", shift(r.dst, offsets[i + 1]), shift(r.src, offsets[i]), r.len).unwrap();
+        }
+    }
+
+    out
+}
+
+/// `find_pivot` binary searches the source ranges and `map_intervals` sweeps
+/// them in order, neither copes with ranges that overlap
+fn check_disjoint_ranges(bytes: &[u8], params: &Params) -> Result<(), String> {
+    let almanac = Day5::parse(bytes, params);
+
+    for (i, m) in almanac.seed_mapping_stack.iter().enumerate() {
+        let ends: Vec<usize> = m.ranges.iter().map(|r| r.src.checked_add(r.len).ok_or_else(|| {
+            format!("map {} has a source range {} long from {}, which ends past {}", i + 1, r.len, r.src, usize::MAX)
+        })).collect::<Result<_, _>>()?;
+
+        for (j, pair) in m.ranges.windows(2).enumerate() {
+            if ends[j] > pair[1].src {
+                return Err(format!("map {} has source ranges {}..{} and {}..{} overlapping", i + 1,
+                    pair[0].src, ends[j], pair[1].src, ends[j + 1]));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_seed_pairs(bytes: &[u8], params: &Params) -> Result<(), String> {
+    let seeds = Day5::parse(bytes, params).seeds.len();
+    if seeds.is_multiple_of(2) {
+        Ok(())
+    } else {
+        Err(format!("{} seed numbers cannot be read as start and length pairs", seeds))
+    }
+}
+
+impl Solution for Day5 {
+    type Model = Almanac;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> Almanac {
+        let mut lines = input::lines(bytes);
+
+        let seeds_to_plant = {
+            let header = lines.next().unwrap();
+            let (_, seed_split) = input::split_once(header, b": ").unwrap();
+            input::fields(seed_split).map(|seed| input::parse_uint(seed).unwrap()).collect()
+        };
+
+        let mappings = MAPS.iter()
+            .map(|name| parse_mappings(&mut lines, format!("{} map:", name).as_bytes()))
+            .collect();
+
+        Almanac {
+            seeds: seeds_to_plant,
+            seed_mapping_stack: mappings,
+        }
+    }
+
+    fn part1(almanac: &Almanac, _cancel: &Cancel) -> Answer {
+        part1(almanac).into()
+    }
+
+    fn part2(almanac: &Almanac, _cancel: &Cancel) -> Answer {
+        part2(almanac).into()
+    }
+
+    fn strategies() -> Vec<Strategy<Almanac>> {
+        vec![
+            Strategy { name: "brute-force", part: 2, solve: |a, cancel| part2_brute_force(a, cancel).into() },
+        ]
+    }
+
+    fn anonymise(bytes: &[u8], rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(shift_ranges(bytes, rng))
+    }
+
+    /// The seeds, and every map by name with its ranges sorted by source
+    fn dump_model(almanac: &Almanac) -> Option<Json> {
+        let maps = MAPS.iter().zip(&almanac.seed_mapping_stack).map(|(name, map)| Json::object([
+            ("name", Json::from(*name)),
+            ("ranges", Json::array(map.ranges.iter().map(|r| r.to_json()))),
+        ]));
+
+        Some(Json::object([
+            ("seeds", Json::array(almanac.seeds.iter().copied())),
+            ("maps", Json::array(maps)),
+        ]))
+    }
+
+    /// The seeds, the map headers parsing checks for and the blank lines between the maps
+    fn pinned_line(index: usize, line: &[u8]) -> bool {
+        index == 0 || line.is_empty() || line.ends_with(b"map:")
+    }
+
+    fn assumptions() -> Vec<Assumption> {
+        vec![
+            Assumption {
+                name: "source ranges within a map do not overlap",
+                relied_on_by: &[(1, "default"), (2, "default"), (2, "brute-force")],
+                check: check_disjoint_ranges,
+            },
+            Assumption {
+                name: "seeds come in start and length pairs",
+                relied_on_by: &[(2, "default"), (2, "brute-force")],
+                check: check_seed_pairs,
+            },
+        ]
+    }
+}
@@ -0,0 +1,141 @@
+use crate::bigint::BigInt;
+use crate::cancel::{Cancel, TimedOut};
+use crate::checked::{Arith, Overflow};
+use crate::config::Params;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+
+#[derive(Debug)]
+struct Race {
+    time_allowed: usize,
+    distance_record: usize,
+}
+
+pub struct Races {
+    races: Vec<Race>,
+    // Kerned numbers can outgrow usize, so they're kept as big integers
+    combined_time: BigInt,
+    combined_distance: BigInt,
+}
+
+pub struct Day6;
+
+const ARITH: Arith = Arith::new(Day6::YEAR, Day6::DAY);
+
+// -------------------------------------------------------------------------- //
+
+fn count_winning_permutations(races: &[Race], cancel: &Cancel) -> Result<Result<usize, Overflow>, TimedOut> {
+    let mut result = 1;
+
+    for (i, r) in races.iter().enumerate() {
+        let mut wins = 0;
+
+        for hold_time in 1..r.time_allowed {
+            if hold_time.is_multiple_of(1 << 20) {
+                cancel.check(|| format!("race {} of {}, {} of {} hold times tried",
+                    i + 1, races.len(), hold_time, r.time_allowed))?;
+            }
+
+            let travel_time = r.time_allowed - hold_time;
+            let speed = hold_time;
+            let dist = match ARITH.mul(speed, travel_time, "speed * travel_time") {
+                Ok(dist) => dist,
+                Err(overflow) => return Ok(Err(overflow)),
+            };
+
+            if dist > r.distance_record {
+                wins += 1;
+            }
+        }
+
+        result = match ARITH.mul(result, wins, "product of winning counts") {
+            Ok(product) => product,
+            Err(overflow) => return Ok(Err(overflow)),
+        };
+    }
+
+    Ok(Ok(result))
+}
+
+/// Binary search for the shortest winning hold time, the winning ones
+/// then form a range symmetric around `time / 2`
+fn count_winning_permutations_big(time: &BigInt, distance: &BigInt) -> BigInt {
+    let one = BigInt::from(1);
+    let two = BigInt::from(2);
+
+    let mut lo = one.clone();
+    let mut hi = time.div_rem(&two).0;
+
+    let wins_with = |hold: &BigInt| &(hold * &(time - hold)) > distance;
+    if !wins_with(&hi) {
+        return BigInt::zero();
+    }
+
+    while lo < hi {
+        let mid = (&lo + &hi).div_rem(&two).0;
+        if wins_with(&mid) {
+            hi = mid;
+        } else {
+            lo = &mid + &one;
+        }
+    }
+
+    &(time - &(&lo * &two)) + &one
+}
+
+fn kerned_winning_permutations(time: &BigInt, distance: &BigInt, cancel: &Cancel) -> Answer {
+    // Hold time times travel time can't overflow as long as the time fits in 32 bits
+    match (time.to_u64(), distance.to_u64()) {
+        (Some(t), Some(d)) if t <= u32::MAX as u64 => {
+            let race = Race { time_allowed: t as usize, distance_record: d as usize };
+            count_winning_permutations(&[race], cancel).into()
+        }
+        _ => count_winning_permutations_big(time, distance).into(),
+    }
+}
+
+impl Solution for Day6 {
+    type Model = Races;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> Races {
+        // Part 1 input
+        let mut lines = input::lines(bytes);
+        let times_str = lines.next().unwrap().strip_prefix(b"Time:").unwrap();
+        let dist_str = lines.next().unwrap().strip_prefix(b"Distance:").unwrap();
+
+        let times: Vec<usize> = input::fields(times_str).filter_map(input::parse_uint).collect();
+        let distances: Vec<usize> = input::fields(dist_str).filter_map(input::parse_uint).collect();
+
+        assert_eq!(times.len(), distances.len());
+        let races: Vec<Race> = times.iter().zip(distances.iter()).map(|(t, d)|
+            Race { time_allowed: *t, distance_record: *d }
+        ).collect();
+
+        // Part 2 input, ignoring the kerning between the numbers
+        let kerned = |s: &[u8]| {
+            let digits: Vec<u8> = input::fields(s).flatten().copied().collect();
+            BigInt::parse_bytes(&digits).unwrap()
+        };
+
+        Races {
+            races,
+            combined_time: kerned(times_str),
+            combined_distance: kerned(dist_str),
+        }
+    }
+
+    fn part1(races: &Races, cancel: &Cancel) -> Answer {
+        count_winning_permutations(&races.races, cancel).into()
+    }
+
+    fn part2(races: &Races, cancel: &Cancel) -> Answer {
+        kerned_winning_permutations(&races.combined_time, &races.combined_distance, cancel)
+    }
+}
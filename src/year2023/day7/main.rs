@@ -0,0 +1,6 @@
+use advent_of_code::year2023::day7::Day7;
+use advent_of_code::solution;
+
+fn main() {
+    solution::main::<Day7>();
+}
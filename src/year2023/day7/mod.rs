@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::Write;
+use crate::cancel::Cancel;
+use crate::config::Params;
+use crate::input::{self, Lines};
+use crate::json::Json;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeKind,
+    FullHouse,
+    FourKind,
+    FiveKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CardHand {
+    cards: [u16; 5],
+    hand_type: HandType,
+    bid: usize,
+}
+
+pub struct CamelCards {
+    plain: Vec<CardHand>,
+    wildcard: Vec<CardHand>,
+}
+
+pub struct Day7;
+
+// -------------------------------------------------------------------------- //
+
+impl fmt::Display for CardHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CardHand {{ '{}', {:?}, bid: {} }}", self.labels(), self.hand_type, self.bid)
+    }
+}
+
+impl CardHand {
+    /// The cards as they were dealt, with jokers shown as `*`
+    fn labels(&self) -> String {
+        let num_to_char = |&n| {
+            match n {
+                1 => '*',
+                2..=9 => ((n + 0x30) as u8) as char,
+                10 => 'T',
+                11 => 'J',
+                12 => 'Q',
+                13 => 'K',
+                14 => 'A',
+                _ => unreachable!(),
+            }
+        };
+        String::from_iter(self.cards.iter().map(num_to_char))
+    }
+
+    fn new(hand: &[u8], bid: usize, use_wildcards: bool) -> CardHand {
+        let hand: &[u8; 5] = hand.try_into().expect("hands have 5 cards");
+
+        let byte_to_num = |c: u8| {
+            match c {
+                b'J' => if use_wildcards { 1 } else { 11 },
+                b'2'..=b'9' => (c - b'0') as u16,
+                b'T' => 10,
+                b'Q' => 12,
+                b'K' => 13,
+                b'A' => 14,
+                _ => unreachable!(),
+            }
+        };
+        let nums: [u16; 5] = hand.map(byte_to_num);
+
+        // Indexed by card value, so counting needs no allocation
+        let mut occurences = [0u16; 15];
+        for &n in &nums {
+            occurences[n as usize] += 1;
+        }
+        let wildcards = occurences[1];
+        occurences[1] = 0;
+
+        let max_occur = occurences.iter().max().unwrap() + wildcards;
+        let distinct = occurences.iter().filter(|&&o| o > 0).count();
+
+        let ht = match distinct {
+            0 => {
+                assert_eq!(wildcards, 5);
+                HandType::FiveKind
+            }
+            1 => HandType::FiveKind,
+            2 => if max_occur == 4 {
+                    HandType::FourKind
+                } else {
+                    HandType::FullHouse
+                },
+            3 => if max_occur == 3 {
+                    HandType::ThreeKind
+                } else {
+                    HandType::TwoPair
+                },
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => unreachable!(),
+        };
+
+        CardHand {
+            cards: nums,
+            hand_type: ht,
+            bid,
+        }
+    }
+
+    fn cmp_strength(&self, other: &CardHand) -> Ordering {
+        let s = self.hand_type.partial_cmp(&other.hand_type).unwrap();
+        match s {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            _ => s,
+        }
+    }
+}
+
+fn parse_and_sort_card_hands(lines: &Lines<'_>, use_wildcard: bool) -> Vec<CardHand> {
+    let mut hands: Vec<CardHand> = Vec::new();
+
+    for line in lines.clone() {
+        let mut iter = input::fields(line);
+        let hand = iter.next().unwrap();
+        let bid = input::parse_uint(iter.next().unwrap()).unwrap();
+        hands.push(CardHand::new(hand, bid, use_wildcard));
+    }
+
+    hands.sort_by(|a: &CardHand, b| {
+        a.cmp_strength(b)
+    });
+
+    hands
+}
+
+fn total_winning(hands: &[CardHand]) -> usize {
+    let mut total = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        let rank = i + 1;
+        let win = rank * hand.bid;
+
+        total += win;
+    }
+    total
+}
+
+/// Deals the bids out to different hands and shuffles the order of the hands
+fn permute_bids(bytes: &[u8], rng: &mut Rng) -> Vec<u8> {
+    let (mut hands, mut bids): (Vec<&[u8]>, Vec<&[u8]>) = input::lines(bytes).map(|line| {
+        let mut iter = input::fields(line);
+        (iter.next().unwrap(), iter.next().unwrap())
+    }).unzip();
+    rng.shuffle(&mut hands);
+    rng.shuffle(&mut bids);
+
+    let mut out = Vec::with_capacity(bytes.len());
+    for (hand, bid) in hands.into_iter().zip(bids) {
+        out.extend_from_slice(hand);
+        out.push(b' ');
+        out.extend_from_slice(bid);
+        out.push(b'\n');
+    }
+    out
+}
+
+/// `hands` random hands of five cards, with bids up to 1000
+fn generate_hands(hands: usize, rng: &mut Rng) -> Vec<u8> {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut out = Vec::new();
+
+    for _ in 0..hands {
+        for _ in 0..5 {
+            out.push(CARDS[rng.below(CARDS.len() as u64) as usize]);
+        }
+        writeln!(out, " {}", 1 + rng.below(1000)).unwrap();
+    }
+    out
+}
+
+impl Solution for Day7 {
+    type Model = CamelCards;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> CamelCards {
+        let lines = input::lines(bytes);
+
+        CamelCards {
+            plain: parse_and_sort_card_hands(&lines, false),
+            wildcard: parse_and_sort_card_hands(&lines, true),
+        }
+    }
+
+    fn part1(cards: &CamelCards, _cancel: &Cancel) -> Answer {
+        total_winning(&cards.plain).into()
+    }
+
+    fn part2(cards: &CamelCards, _cancel: &Cancel) -> Answer {
+        total_winning(&cards.wildcard).into()
+    }
+
+    fn render(cards: &CamelCards) -> Option<String> {
+        let ranked = cards.wildcard.iter().enumerate().map(|(i, hand)| {
+            format!("[rank {}] {}\n", i + 1, hand)
+        });
+        Some(String::from_iter(ranked))
+    }
+
+    fn dump_model(cards: &CamelCards) -> Option<Json> {
+        let ranked = |hands: &[CardHand]| Json::array(hands.iter().enumerate().map(|(i, hand)| Json::object([
+            ("rank", Json::from(i + 1)),
+            ("cards", Json::from(hand.labels())),
+            ("type", Json::from(format!("{:?}", hand.hand_type))),
+            ("bid", Json::from(hand.bid)),
+        ])));
+
+        Some(Json::object([("plain", ranked(&cards.plain)), ("wildcard", ranked(&cards.wildcard))]))
+    }
+
+    fn anonymise(bytes: &[u8], rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(permute_bids(bytes, rng))
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(generate_hands(size, rng))
+    }
+}
@@ -0,0 +1,6 @@
+use advent_of_code::year2023::day8::Day8;
+use advent_of_code::solution;
+
+fn main() {
+    solution::main::<Day8>();
+}
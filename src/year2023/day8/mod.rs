@@ -0,0 +1,363 @@
+use std::fmt;
+use crate::bigint::BigInt;
+use crate::cancel::{Cancel, TimedOut};
+use crate::args::Kind;
+use crate::config::{Param, Params};
+use crate::graph::{self, Graph, Interner};
+use crate::hash::{FxHashMap, FxHashSet};
+use crate::input;
+use crate::json::Json;
+use crate::rng::Rng;
+use crate::solution::{Answer, Assumption, Solution};
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    id: [u8; 3],
+}
+
+/// Every node has two edges, to its left then its right successor
+#[derive(Debug, Clone)]
+struct Network {
+    nodes: Interner<Node>,
+    graph: Graph,
+}
+
+/// Position of a walk through the network: the current node and the next instruction
+type State = (usize, usize);
+
+pub struct Documents {
+    instructions: Vec<u8>,
+    network: Network,
+    start: Node,
+    end: Node,
+}
+
+pub struct Day8;
+
+// -------------------------------------------------------------------------- //
+
+impl Node {
+    fn new(node_id: &[u8]) -> Node {
+        let id = node_id.trim_ascii();
+        Node {
+            id: id.try_into().expect("node ids are 3 bytes"),
+        }
+    }
+
+    fn last_char(&self) -> u8 {
+        self.id[2]
+    }
+
+    fn label(&self) -> String {
+        String::from_iter(self.id.iter().map(|c| *c as char))
+    }
+}
+
+impl Network {
+    fn next(&self, node: usize, lr: u8) -> usize {
+        let edges = self.graph.edges(node);
+        match lr {
+            b'L' => edges[0].to,
+            b'R' => edges[1].to,
+            _ => unreachable!(),
+        }
+    }
+
+    fn id(&self, node: Node) -> usize {
+        self.nodes.get(&node).unwrap_or_else(|| panic!("{:?} is not in the network", node))
+    }
+}
+
+impl Documents {
+    fn step(&self, (node, i): State) -> State {
+        let next = self.network.next(node, self.instructions[i]);
+        (next, (i + 1) % self.instructions.len())
+    }
+}
+
+impl fmt::Debug for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Node {{ \"{}\" }}", self.label())
+    }
+}
+
+// -------------------------------------------------------------------------- //
+
+fn gcd(a: usize, b: usize) -> usize {
+    let mut x = a;
+    let mut y = b;
+    while y > 0 {
+        (x, y) = (y, x % y);
+    }
+    x
+}
+
+fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// LCM of all cycle lengths, switching to big integers once it outgrows usize
+fn lcm_all(values: &[usize]) -> Answer {
+    let mut acc = values[0];
+
+    for (i, &v) in values.iter().enumerate().skip(1) {
+        match checked_lcm(acc, v) {
+            Some(l) => acc = l,
+            None => {
+                let mut big = BigInt::from(acc);
+                for &w in &values[i..] {
+                    let rem = big.div_rem(&BigInt::from(w)).1.to_u64().unwrap() as usize;
+                    let divisor = w / gcd(w, rem);
+                    big = &big * &BigInt::from(divisor);
+                }
+                return big.into();
+            }
+        }
+    }
+
+    acc.into()
+}
+
+fn part1(docs: &Documents, cancel: &Cancel) -> Result<usize, TimedOut> {
+    let network = &docs.network;
+    let end = network.id(docs.end);
+    let mut steps = 0;
+    let mut current_node = network.id(docs.start);
+
+    'outer: loop {
+        cancel.check(|| format!("{} steps taken, at {:?}", steps, network.nodes.key(current_node)))?;
+
+        for &lr in &docs.instructions {
+            steps += 1;
+            current_node = network.next(current_node, lr);
+
+            if current_node == end {
+                break 'outer;
+            }
+        }
+    }
+    Ok(steps)
+}
+
+/// Each ghost lands on its `..Z` again every as many steps as it took to get there
+/// first, so they all meet on one after the LCM of those first step counts
+fn part2(docs: &Documents, cancel: &Cancel) -> Result<Answer, TimedOut> {
+    let nodes = &docs.network.nodes;
+    let starts: Vec<usize> = nodes.iter()
+        .filter(|(_, n)| n.last_char() == b'A')
+        .map(|(id, _)| id)
+        .collect();
+    let states = nodes.len() * docs.instructions.len();
+
+    let periods: Result<Vec<usize>, TimedOut> = starts.iter().enumerate().map(|(i, &start)| {
+        let mut state = (start, 0);
+        for steps in 1..=states {
+            state = docs.step(state);
+            if nodes.key(state.0).last_char() == b'Z' {
+                return Ok(steps);
+            }
+            if steps.is_multiple_of(4096) {
+                cancel.check(|| format!("ghost {} of {} from {:?} after {} steps",
+                    i + 1, starts.len(), nodes.key(start), steps))?;
+            }
+        }
+        panic!("ghost from {:?} never reaches a ..Z", nodes.key(start));
+    }).collect();
+
+    Ok(lcm_all(&periods?))
+}
+
+/// Follows the walk from `start` for `steps` steps, returning the nodes for which
+/// `is_end` holds along with the step at which it was on them
+fn ends_within(docs: &Documents, start: usize, steps: usize, is_end: impl Fn(Node) -> bool) -> Vec<(usize, Node)> {
+    let mut state = (start, 0);
+    let mut ends = Vec::new();
+    for step in 1..=steps {
+        state = docs.step(state);
+        let node = *docs.network.nodes.key(state.0);
+        if is_end(node) {
+            ends.push((step, node));
+        }
+    }
+    ends
+}
+
+fn check_reaches_end(bytes: &[u8], params: &Params) -> Result<(), String> {
+    let docs = Day8::parse(bytes, params);
+    let start = docs.network.id(docs.start);
+    let cycle = graph::brent((start, 0), |state| docs.step(state));
+
+    if ends_within(&docs, start, cycle.start + cycle.len, |n| n == docs.end).is_empty() {
+        return Err(format!("walking from {:?} never reaches {:?}, it loops every {} steps after {}",
+            docs.start, docs.end, cycle.len, cycle.start));
+    }
+    Ok(())
+}
+
+/// `part2` takes the step count of each ghost's first `..Z` as its period, which only
+/// works if that is the only `..Z` it meets and it is there again at every multiple
+/// of that count and never in between
+fn check_ghost_periods(bytes: &[u8], params: &Params) -> Result<(), String> {
+    let docs = Day8::parse(bytes, params);
+
+    for (start, node) in docs.network.nodes.iter().filter(|(_, n)| n.last_char() == b'A') {
+        // Twice round the cycle shows every step at which it is on a ..Z from then on
+        let cycle = graph::brent((start, 0), |state| docs.step(state));
+        let walked = 2 * (cycle.start + cycle.len);
+        let ends = ends_within(&docs, start, walked, |n| n.last_char() == b'Z');
+
+        let Some(&(t1, first)) = ends.first() else {
+            return Err(format!("ghost from {:?} never reaches a ..Z", node));
+        };
+        if let Some((t, other)) = ends.iter().find(|(_, n)| *n != first) {
+            return Err(format!("ghost from {:?} reaches {:?} after {} steps but then {:?} after {}",
+                node, first, t1, other, t));
+        }
+        let steps: Vec<usize> = ends.iter().map(|(t, _)| *t).collect();
+        if !steps.iter().copied().eq((t1..=walked).step_by(t1)) {
+            return Err(format!("ghost from {:?} reaches {:?} after {} steps but then after steps {:?}",
+                node, first, t1, steps));
+        }
+    }
+    Ok(())
+}
+
+/// Gives every node a random new id with the same last letter, so the `..A` starts
+/// and `..Z` ends are kept, and the part 1 `AAA` and `ZZZ` keep their names
+fn relabel_network(bytes: &[u8], rng: &mut Rng) -> Vec<u8> {
+    let mut lines = input::lines(bytes);
+    let instructions = lines.next().unwrap();
+    assert_eq!(lines.next(), Some(&b""[..]));
+
+    let fixed = [Node { id: *b"AAA" }, Node { id: *b"ZZZ" }];
+    let mut labels: FxHashMap<Node, Node> = fixed.iter().map(|&n| (n, n)).collect();
+    let mut taken: FxHashSet<Node> = fixed.into_iter().collect();
+
+    let mut relabel = |node: &[u8]| -> Node {
+        let node = Node::new(node);
+        *labels.entry(node).or_insert_with(|| loop {
+            let letter = |rng: &mut Rng| b'A' + rng.below(26) as u8;
+            let new = Node { id: [letter(rng), letter(rng), node.last_char()] };
+            if taken.insert(new) {
+                break new;
+            }
+        })
+    };
+
+    let mut nodes: Vec<[Node; 3]> = lines.map(|line| {
+        let (node, left_right) = input::split_once(line, b" = ").unwrap();
+        let left_right = left_right.strip_prefix(b"(").unwrap().strip_suffix(b")").unwrap();
+        let (left, right) = input::split_once(left_right, b", ").unwrap();
+        [relabel(node), relabel(left), relabel(right)]
+    }).collect();
+    rng.shuffle(&mut nodes);
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(instructions);
+    out.extend_from_slice(b"\n\n");
+    for [node, left, right] in nodes {
+        out.extend_from_slice(&node.id);
+        out.extend_from_slice(b" = (");
+        out.extend_from_slice(&left.id);
+        out.extend_from_slice(b", ");
+        out.extend_from_slice(&right.id);
+        out.extend_from_slice(b")\n");
+    }
+    out
+}
+
+impl Solution for Day8 {
+    type Model = Documents;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], params: &Params) -> Documents {
+        let mut lines = input::lines(bytes);
+        let instructions = lines.next().unwrap().trim_ascii();
+        assert_eq!(lines.next(), Some(&b""[..]));
+
+        let mut nodes: Interner<Node> = Interner::new();
+        let mut graph = Graph::default();
+
+        for line in lines {
+            let (node, left_right) = input::split_once(line, b" = ").unwrap();
+            let node = nodes.intern(Node::new(node));
+
+            let left_right = left_right.strip_prefix(b"(").unwrap().strip_suffix(b")").unwrap();
+            let (left, right) = input::split_once(left_right, b", ").unwrap();
+            let left = nodes.intern(Node::new(left));
+            let right = nodes.intern(Node::new(right));
+
+            graph.add_edge(node, left, ());
+            graph.add_edge(node, right, ());
+        }
+
+        Documents {
+            instructions: instructions.to_vec(),
+            network: Network { nodes, graph },
+            start: Node::new(params.string("start", "AAA").as_bytes()),
+            end: Node::new(params.string("end", "ZZZ").as_bytes()),
+        }
+    }
+
+    fn part1(docs: &Documents, cancel: &Cancel) -> Answer {
+        part1(docs, cancel).into()
+    }
+
+    fn part2(docs: &Documents, cancel: &Cancel) -> Answer {
+        part2(docs, cancel).into()
+    }
+
+    /// Nodes map to their left and right successors, or to nothing if the input
+    /// only refers to them
+    fn dump_model(docs: &Documents) -> Option<Json> {
+        let network = &docs.network;
+        let nodes = network.nodes.iter().map(|(id, node)| {
+            let successors = network.graph.neighbours(id).map(|next| network.nodes.key(next).label());
+            (node.label(), Json::array(successors))
+        });
+
+        Some(Json::object([
+            ("instructions", Json::from(String::from_utf8_lossy(&docs.instructions).into_owned())),
+            ("start", Json::from(docs.start.label())),
+            ("end", Json::from(docs.end.label())),
+            ("nodes", Json::object(nodes)),
+        ]))
+    }
+
+    fn anonymise(bytes: &[u8], rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(relabel_network(bytes, rng))
+    }
+
+    /// The instructions and the blank line after them, leaving the nodes
+    fn pinned_line(index: usize, _line: &[u8]) -> bool {
+        index < 2
+    }
+
+    fn params() -> Vec<Param> {
+        vec![
+            Param { key: "start", kind: Kind::Str },
+            Param { key: "end", kind: Kind::Str },
+        ]
+    }
+
+    fn assumptions() -> Vec<Assumption> {
+        vec![
+            Assumption {
+                name: "the part 1 start reaches the end",
+                relied_on_by: &[(1, "default")],
+                check: check_reaches_end,
+            },
+            Assumption {
+                name: "each ghost revisits its first ..Z with the same period",
+                relied_on_by: &[(2, "default")],
+                check: check_ghost_periods,
+            },
+        ]
+    }
+}
@@ -0,0 +1,140 @@
+use std::io::Write;
+use crate::cancel::Cancel;
+use crate::config::Params;
+use crate::input;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+
+pub struct Day9;
+
+// -------------------------------------------------------------------------- //
+
+fn derivatives(values: &[i64]) -> Vec<i64> {
+    assert!(values.len() > 1);
+
+    values.windows(2).map(|w| {
+        match *w {
+            [a, b] => b - a,
+            _ => unreachable!(),
+        }
+    }).collect()
+}
+
+fn calculate_derivatives(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut nth_deriv: Vec<Vec<i64>> = vec![Vec::from(values)];
+
+    loop {
+        let mut mini = i64::MAX;
+        let mut maxi = i64::MIN;
+        let last_deriv = nth_deriv.last().unwrap();
+
+        last_deriv.iter().for_each(|d| {
+            mini = mini.min(*d);
+            maxi = maxi.max(*d);
+        });
+        
+        if mini == maxi {
+            nth_deriv.push(Vec::from_iter([0]));
+            break;
+        }
+
+        nth_deriv.push(derivatives(last_deriv));
+    }
+
+    nth_deriv
+}
+
+fn part1(histories: &[Vec<i64>]) -> i64 {
+    let mut sum = 0;
+
+    for history in histories {
+        let mut nth_deriv = calculate_derivatives(history);
+
+        for fwd_i in 1..nth_deriv.len() {
+            let i = nth_deriv.len() - fwd_i - 1;
+
+            let higher_deriv = *nth_deriv[i + 1].last().unwrap();
+            let curr_deriv = nth_deriv[i].last().unwrap();
+
+            let extrap = curr_deriv + higher_deriv;
+            nth_deriv[i].push(extrap);
+        }
+
+        let extrapolated = *nth_deriv[0].last().unwrap();
+        sum += extrapolated;
+    }
+
+    sum
+}
+
+fn part2(histories: &[Vec<i64>]) -> i64 {
+    let mut sum = 0;
+
+    for history in histories {
+        let mut nth_deriv = calculate_derivatives(history);
+
+        for fwd_i in 1..nth_deriv.len() {
+            let i = nth_deriv.len() - fwd_i - 1;
+
+            let higher_deriv = *nth_deriv[i + 1].first().unwrap();
+            let curr_deriv = nth_deriv[i].first().unwrap();
+
+            let extrap = curr_deriv - higher_deriv;
+            nth_deriv[i].insert(0, extrap);
+        }
+
+        let extrapolated = *nth_deriv[0].first().unwrap();
+        sum += extrapolated;
+    }
+
+    sum
+}
+
+/// `histories` lines of 21 values of a random polynomial of degree up to 5
+fn generate_histories(histories: usize, rng: &mut Rng) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    for _ in 0..histories {
+        let coefficients: Vec<i64> = (0..1 + rng.below(6)).map(|_| rng.below(21) as i64 - 10).collect();
+        for x in 0..21 {
+            let value = coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+            write!(out, "{}{}", if x > 0 { " " } else { "" }, value).unwrap();
+        }
+        out.push(b'\n');
+    }
+    out
+}
+
+impl Solution for Day9 {
+    type Model = Vec<Vec<i64>>;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    #[cfg(feature = "embed-inputs")]
+    const INPUT: Option<&'static [u8]> = Some(include_bytes!("input.txt"));
+
+    fn parse(bytes: &[u8], _params: &Params) -> Vec<Vec<i64>> {
+        let mut histories: Vec<Vec<i64>> = Vec::new();
+
+        for line in input::lines(bytes) {
+            let hist: Vec<i64> = input::fields(line).map(|v| input::parse_int(v).unwrap()).collect();
+            histories.push(hist);
+        }
+
+        histories
+    }
+
+    fn part1(histories: &Vec<Vec<i64>>, _cancel: &Cancel) -> Answer {
+        part1(histories).into()
+    }
+
+    fn part2(histories: &Vec<Vec<i64>>, _cancel: &Cancel) -> Answer {
+        part2(histories).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Vec<u8>> {
+        Some(generate_histories(size, rng))
+    }
+}
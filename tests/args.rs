@@ -0,0 +1,44 @@
+use std::time::Duration;
+use advent_of_code::args::{self, DayOption, Kind};
+use advent_of_code::config::Value;
+use advent_of_code::solution::Solution;
+use advent_of_code::year2023::day2::Day2;
+
+
+fn parse(line: &str) -> Result<args::Args, String> {
+    let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+    args::parse(&args, &Day2::options())
+}
+
+#[test]
+fn common_and_day_options() {
+    let args = parse("input.txt --part=2 -v --limits 1,2,3").unwrap();
+    assert_eq!(args.input.as_deref(), Some("input.txt"));
+    assert_eq!(args.part, Some(2));
+    assert!(args.verbose);
+    assert_eq!(args.overrides, [("max_hand", Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]))]);
+
+    assert_eq!(parse("--input=a.txt").unwrap().input.as_deref(), Some("a.txt"));
+    assert_eq!(parse("--timeout 1.5").unwrap().timeout, Some(Duration::from_millis(1500)));
+    assert!(parse("--help").unwrap().help);
+}
+
+#[test]
+fn helpful_errors() {
+    assert_eq!(parse("--limit 1,2,3").unwrap_err(), "unknown option '--limit', did you mean '--limits'?");
+    assert_eq!(parse("--frobnicate").unwrap_err(), "unknown option '--frobnicate'");
+    assert_eq!(parse("--limits 1,2").unwrap_err(), "--limits needs 3 comma separated non-negative integers, got '1,2'");
+    assert_eq!(parse("--limits 1,-2,3").unwrap_err(), "--limits needs 3 comma separated non-negative integers, got '1,-2,3'");
+    assert_eq!(parse("--part 3").unwrap_err(), "--part must be 1 or 2, got '3'");
+    assert_eq!(parse("--input").unwrap_err(), "--input needs a value");
+    assert_eq!(parse("--timeout 0").unwrap_err(), "--timeout needs a positive number of seconds");
+    assert_eq!(parse("a.txt b.txt").unwrap_err(), "unexpected argument 'b.txt'");
+}
+
+#[test]
+fn usage_lists_day_options() {
+    let options = [DayOption { name: "expansion", param: "part2_expansion", kind: Kind::Int, help: "rows added" }];
+    let usage = args::usage("day11", &options);
+    assert!(usage.starts_with("usage: day11 [<input>] [options]"));
+    assert!(usage.lines().any(|l| l.starts_with("  --expansion <n> ") && l.ends_with("  rows added")));
+}
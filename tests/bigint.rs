@@ -0,0 +1,104 @@
+use advent_of_code::bigint::BigInt;
+
+
+/// Values around the 32-bit limb boundaries and their negations
+fn edges() -> Vec<i128> {
+    let mut values = vec![0, 1, 2, 3, 10, 999_999_999, 1_000_000_000];
+    for shift in [31, 32, 63, 64, 95, 96] {
+        let p = 1i128 << shift;
+        values.extend([p - 1, p, p + 1]);
+    }
+    let negated: Vec<i128> = values.iter().map(|v| -v).collect();
+    values.extend(negated);
+    values
+}
+
+fn big(n: i128) -> BigInt {
+    BigInt::from(n)
+}
+
+#[test]
+fn arithmetic_agrees_with_i128() {
+    let values = edges();
+    for &a in &values {
+        for &b in &values {
+            assert_eq!(big(a) + big(b), big(a + b), "{} + {}", a, b);
+            assert_eq!(big(a) - big(b), big(a - b), "{} - {}", a, b);
+            assert_eq!(big(a).cmp(&big(b)), a.cmp(&b), "{} cmp {}", a, b);
+
+            // Keep products and quotients where i128 can check them
+            if a.unsigned_abs() < 1 << 64 && b.unsigned_abs() < 1 << 63 {
+                assert_eq!(big(a) * big(b), big(a * b), "{} * {}", a, b);
+            }
+            if b != 0 {
+                assert_eq!(big(a).div_rem(&big(b)), (big(a / b), big(a % b)), "{} / {}", a, b);
+            }
+        }
+        assert_eq!(big(a).to_i128(), Some(a));
+    }
+}
+
+#[test]
+fn carries_and_borrows_across_limbs() {
+    let max64 = big(u64::MAX as i128);
+    assert_eq!((&max64 + &big(1)).to_string(), "18446744073709551616");
+    assert_eq!((&big(1i128 << 64) - &big(1)), max64);
+    assert_eq!((&big(1i128 << 96) - &big(1)).to_string(), "79228162514264337593543950335");
+
+    let max128: BigInt = "340282366920938463463374607431768211455".parse().unwrap();
+    assert_eq!((&max128 + &big(1)).to_string(), "340282366920938463463374607431768211456");
+    assert_eq!((&max128 * &max128).to_string(),
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025");
+    assert_eq!(max128.to_i128(), None);
+}
+
+#[test]
+fn multi_limb_division() {
+    let a: BigInt = "123456789012345678901234567890123456789012345678901234567890".parse().unwrap();
+    let b: BigInt = "987654321098765432109876543210".parse().unwrap();
+    let (q, r) = a.div_rem(&b);
+
+    assert_eq!(q.to_string(), "124999998860937500014238281249");
+    assert_eq!(r.to_string(), "935329860093532986009353298600");
+    assert_eq!(&(&q * &b) + &r, a);
+    assert!(r < b && !r.is_negative());
+
+    let (q, r) = (-a.clone()).div_rem(&b);
+    assert_eq!(q.to_string(), "-124999998860937500014238281249");
+    assert!(r.is_negative());
+    assert_eq!(&(&q * &b) + &r, -a.clone());
+
+    assert_eq!(b.div_rem(&a), (BigInt::zero(), b.clone()));
+}
+
+#[test]
+fn zero_has_no_sign() {
+    let zero = BigInt::zero();
+    assert_eq!(-zero.clone(), zero);
+    assert_eq!("-0".parse::<BigInt>().unwrap(), zero);
+    assert!(!"-0".parse::<BigInt>().unwrap().is_negative());
+    assert_eq!(&big(5) - &big(5), zero);
+    assert_eq!(&big(-5) * &big(0), zero);
+    assert!(!(&big(-5) * &big(0)).is_negative());
+    assert_eq!(big(-3).div_rem(&big(7)), (zero.clone(), big(-3)));
+    assert_eq!(zero.to_string(), "0");
+}
+
+#[test]
+fn parse_display_round_trip() {
+    for text in [
+        "0", "7", "-7", "999999999", "1000000000", "-1000000000", "4294967295", "4294967296",
+        "18446744073709551615", "-18446744073709551616", "1000000000000000000000000000000000000001",
+    ] {
+        assert_eq!(text.parse::<BigInt>().unwrap().to_string(), text);
+    }
+    assert_eq!("+42".parse::<BigInt>().unwrap(), big(42));
+    assert_eq!("000123".parse::<BigInt>().unwrap().to_string(), "123");
+
+    for bad in ["", "-", "+", "12a", " 1", "1 ", "--1", "1.5"] {
+        assert!(bad.parse::<BigInt>().is_err(), "{:?}", bad);
+    }
+    for &n in &edges() {
+        assert_eq!(big(n).to_string(), n.to_string());
+    }
+}
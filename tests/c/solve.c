@@ -0,0 +1,118 @@
+/* Solves every day through the C API and checks the answers, run by tests/ffi.rs
+ * as solve <repository root> */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc.h"
+
+/* NULL where the part is unsolved */
+static const struct {
+    uint8_t day;
+    const char *part1;
+    const char *part2;
+} EXPECTED[] = {
+    {1, "54605", "55429"},
+    {2, "2369", "66363"},
+    {3, "507214", "72553319"},
+    {4, "18653", "5921508"},
+    {5, "579439039", "7873084"},
+    {6, "1108800", "36919753"},
+    {7, "250602641", "251037509"},
+    {8, "22411", "11188774513823"},
+    {9, "1637452029", "908"},
+    {10, "6875", "471"},
+    {11, "10292708", "790194712336"},
+};
+
+static int failures = 0;
+
+static void check(int ok, const char *what) {
+    if (!ok) {
+        fprintf(stderr, "FAILED: %s\n", what);
+        failures++;
+    }
+}
+
+static uint8_t *read_file(const char *path, size_t *len) {
+    FILE *f = fopen(path, "rb");
+    if (!f) {
+        return NULL;
+    }
+    fseek(f, 0, SEEK_END);
+    *len = (size_t)ftell(f);
+    fseek(f, 0, SEEK_SET);
+
+    uint8_t *bytes = malloc(*len ? *len : 1);
+    if (fread(bytes, 1, *len, f) != *len) {
+        free(bytes);
+        bytes = NULL;
+    }
+    fclose(f);
+    return bytes;
+}
+
+static void solve_day(const char *root, uint8_t day, const char *part1, const char *part2) {
+    char path[4096];
+    snprintf(path, sizeof path, "%s/src/year2023/day%u/input.txt", root, day);
+
+    size_t len;
+    uint8_t *input = read_file(path, &len);
+    if (!input) {
+        fprintf(stderr, "FAILED: cannot read %s\n", path);
+        failures++;
+        return;
+    }
+
+    const char *expected[] = {part1, part2};
+    for (uint8_t part = 1; part <= 2; part++) {
+        char answer[64];
+        int status = aoc_solve(day, part, input, len, answer, sizeof answer);
+        const char *want = expected[part - 1];
+
+        printf("day %u part %u: status %d, %s\n", day, part, status, answer);
+        if (want) {
+            check(status == AOC_OK && strcmp(answer, want) == 0, "answer matches");
+        } else {
+            check(status == AOC_UNSOLVED, "unsolved part reports AOC_UNSOLVED");
+        }
+    }
+    free(input);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <repository root>\n", argv[0]);
+        return 2;
+    }
+
+    for (size_t i = 0; i < sizeof EXPECTED / sizeof EXPECTED[0]; i++) {
+        solve_day(argv[1], EXPECTED[i].day, EXPECTED[i].part1, EXPECTED[i].part2);
+    }
+
+    const uint8_t example[] = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+    char out[64];
+
+    check(aoc_solve(9, 1, example, sizeof example - 1, out, sizeof out) == AOC_OK
+        && strcmp(out, "114") == 0, "day 9 example");
+    check(aoc_solve_year(2023, 9, 2, example, sizeof example - 1, out, sizeof out) == AOC_OK
+        && strcmp(out, "2") == 0, "day 9 example by year");
+    check(aoc_solve(9, 1, example, sizeof example - 1, out, 3) == AOC_BUFFER_TOO_SMALL
+        && out[0] == '\0', "answer too long for the buffer");
+    check(aoc_solve(12, 1, example, sizeof example - 1, out, sizeof out) == AOC_NO_SUCH_DAY, "unknown day");
+    check(aoc_solve(9, 3, example, sizeof example - 1, out, sizeof out) == AOC_NO_SUCH_DAY, "unknown part");
+    check(aoc_solve_year(1999, 9, 1, example, sizeof example - 1, out, sizeof out) == AOC_NO_SUCH_DAY, "unknown year");
+    check(aoc_solve(9, 1, NULL, 0, out, sizeof out) == AOC_INVALID_ARGUMENT, "NULL input");
+    check(aoc_solve(9, 1, example, sizeof example - 1, NULL, 0) == AOC_INVALID_ARGUMENT, "NULL output");
+
+    const uint8_t garbage[] = "not a network\n";
+    check(aoc_solve(8, 1, garbage, sizeof garbage - 1, out, sizeof out) == AOC_PANICKED
+        && out[0] != '\0', "malformed input reports why");
+
+    if (failures) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "checked")]
+
+use advent_of_code::checked::Overflow;
+use advent_of_code::config::{Params, Value};
+use advent_of_code::solution::{run, run_part, Answer, RunOptions};
+use advent_of_code::year2023::day2::Day2;
+use advent_of_code::year2023::day4::Day4;
+use advent_of_code::year2023::day11::Day11;
+
+
+fn overflow(day: u8, computation: &'static str) -> Answer {
+    Answer::Overflow(Overflow { year: 2023, day, computation })
+}
+
+#[test]
+fn day2_power() {
+    let input = "Game 1: 2000 red, 2000 green, 2000 blue\n";
+    let run = run::<Day2>(input.as_bytes(), &Params::default(), RunOptions::default());
+
+    assert_eq!(run.part1, Answer::from(0));
+    assert_eq!(run.part2, overflow(2, "red * green * blue"));
+}
+
+#[test]
+fn day2_cubes_in_a_hand() {
+    let input = "Game 1: 2147483647 red, 1 red\n";
+    let run = run::<Day2>(input.as_bytes(), &Params::default(), RunOptions::default());
+
+    assert_eq!(run.part1, overflow(2, "cubes of a colour in a hand"));
+    assert_eq!(run.part2, overflow(2, "cubes of a colour in a hand"));
+}
+
+/// A card on which all of the first `wins` numbers you have are winning ones
+fn card(id: usize, wins: u32) -> String {
+    let numbers: Vec<String> = (1..=wins).map(|n| n.to_string()).collect();
+    format!("Card {}: {} | {}\n", id, numbers.join(" "), numbers.join(" "))
+}
+
+#[test]
+fn day4_points() {
+    let part1 = |input: &str| run_part::<Day4>(input.as_bytes(), &Params::default(), 1, RunOptions::default()).answer;
+
+    assert_eq!(part1(&card(1, 64)), Answer::from(1u64 << 63));
+    assert_eq!(part1(&card(1, 65)), overflow(4, "1 << (win_count - 1)"));
+    assert_eq!(part1(&(card(1, 64) + &card(2, 64))), overflow(4, "sum of points"));
+}
+
+#[test]
+fn day11_expansion() {
+    let input = "#..\n...\n...\n";
+    let mut params = Params::default();
+    params.set("part2_expansion", Value::Int(i64::MAX));
+    let run = run::<Day11>(input.as_bytes(), &params, RunOptions::default());
+
+    assert_eq!(run.part1, Answer::from(0));
+    assert_eq!(run.part2, overflow(11, "row expansion"));
+    assert_eq!(run.part2.to_string(), "overflow (2023 day 11: row expansion overflowed)");
+}
@@ -0,0 +1,36 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+
+/// Set to re-bless all snapshots from the current output instead of comparing
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+fn snapshot_path(name: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests");
+    path.push("snapshots");
+    path.push(format!("{}.snap", name));
+    path
+}
+
+/// Compares rendered text against `tests/snapshots/<name>.snap`
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if env::var_os(UPDATE_ENV).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing snapshot {}, run with {}=1 to create it", path.display(), UPDATE_ENV)
+    });
+
+    if expected != actual {
+        panic!("snapshot {} does not match, run with {}=1 to update it\n\
+            --- expected ---\n{}\n--- actual ---\n{}",
+            path.display(), UPDATE_ENV, expected, actual);
+    }
+}
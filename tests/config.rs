@@ -0,0 +1,105 @@
+use advent_of_code::config::{Config, Value};
+
+
+#[test]
+fn sections() {
+    let config = Config::parse("\
+[day11]
+part2_expansion = 999_999
+
+[day11.alice]
+part2_expansion = 99
+
+[day3.\"bob.txt\"]
+width = 10
+
+[2022.day5]
+seeds = 1
+").unwrap();
+
+    assert_eq!(config.params(2023, 11, "input.txt").get("part2_expansion"), Some(&Value::Int(999_999)));
+    assert_eq!(config.params(2023, 11, "inputs/alice.txt").get("part2_expansion"), Some(&Value::Int(99)));
+    assert_eq!(config.params(2023, 3, "bob.txt").get("width"), Some(&Value::Int(10)));
+    assert_eq!(config.params(2023, 3, "input.txt").get("width"), None);
+    assert_eq!(config.params(2022, 5, "input.txt").get("seeds"), Some(&Value::Int(1)));
+    assert_eq!(config.params(2023, 5, "input.txt").get("seeds"), None);
+}
+
+#[test]
+fn values_and_comments() {
+    let config = Config::parse("\
+# leading comment
+[day8]   # trailing comment
+start = \"A#A\"   # the # inside the string stays
+end = \"\"
+max_hand = [12, 13, 14]
+empty = []
+flag = true
+").unwrap();
+    let params = config.params(2023, 8, "input.txt");
+
+    assert_eq!(params.get("start"), Some(&Value::Str(String::from("A#A"))));
+    assert_eq!(params.get("end"), Some(&Value::Str(String::new())));
+    assert_eq!(params.get("max_hand"), Some(&Value::Array(vec![Value::Int(12), Value::Int(13), Value::Int(14)])));
+    assert_eq!(params.get("empty"), Some(&Value::Array(Vec::new())));
+    assert_eq!(params.get("flag"), Some(&Value::Bool(true)));
+}
+
+#[test]
+fn strings_and_arrays() {
+    let config = Config::parse(r#"
+[day8]
+start = "say \"hi\" # not a comment"
+end = "back\\slash"
+list = ["a,b", "c]", 1, [2, 3],]
+spaced = [ 1 ,2 , 3 ]
+"#).unwrap();
+    let params = config.params(2023, 8, "input.txt");
+    let str = |s: &str| Value::Str(String::from(s));
+
+    assert_eq!(params.get("start"), Some(&str("say \"hi\" # not a comment")));
+    assert_eq!(params.get("end"), Some(&str("back\\slash")));
+    assert_eq!(params.get("list"), Some(&Value::Array(vec![
+        str("a,b"), str("c]"), Value::Int(1), Value::Array(vec![Value::Int(2), Value::Int(3)]),
+    ])));
+    assert_eq!(params.get("spaced"), Some(&Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+}
+
+#[test]
+fn malformed_lines() {
+    let error = |text: &str| Config::parse(text).unwrap_err();
+
+    assert_eq!(error("[day3\nwidth = 1"), "line 1: unterminated section header");
+    assert_eq!(error("[week3]"), "line 1: expected [dayN], [dayN.input] or [YYYY.dayN]");
+    assert_eq!(error("[day3]\nwidth 140"), "line 2: expected key = value");
+    assert_eq!(error("[day3]\nmy-width = 1"), "line 2: invalid key 'my-width'");
+    assert_eq!(error("[day3]\nsymbols = \"*#"), "line 2: invalid value for 'symbols'");
+    assert_eq!(error("[day3]\nwidth = 1x"), "line 2: invalid value for 'width'");
+    assert_eq!(error("width = 140"), "line 1: key 'width' outside of a [dayN] section");
+    assert_eq!(error("[day2]\nmax_hand = [1,,2]"), "line 2: invalid value for 'max_hand'");
+    assert_eq!(error("[day2]\nmax_hand = [,1]"), "line 2: invalid value for 'max_hand'");
+    assert_eq!(error("[day2]\nmax_hand = [1 2]"), "line 2: invalid value for 'max_hand'");
+    assert_eq!(error("[day2]\nmax_hand = [1, 2"), "line 2: invalid value for 'max_hand'");
+    assert_eq!(error("[day8]\nstart = \"AAA\" \"BBB\""), "line 2: invalid value for 'start'");
+    assert_eq!(error("[day8]\nstart = \"A\\qA\""), "line 2: invalid value for 'start'");
+}
+
+#[test]
+fn checked_against_declared_params() {
+    let check = |text: &str| Config::parse(text).unwrap().check();
+
+    assert_eq!(check("[day2]\nmax_hand = [1, 2, 3]\n[day11.alice]\npart2_expansion = 9"), Ok(()));
+    assert_eq!(
+        check("[day2]\nmax_hand = [1, 2]"),
+        Err(String::from("[day2]: 'max_hand' must be an array of 3 non-negative integers, got [1, 2]")),
+    );
+    assert_eq!(
+        check("[day11.alice]\npart2_expansion = \"big\""),
+        Err(String::from("[day11.alice]: 'part2_expansion' must be an integer, got \"big\"")),
+    );
+    assert_eq!(check("[day8]\nstart = 1"), Err(String::from("[day8]: 'start' must be a string, got 1")));
+    assert_eq!(check("[day3]\nwidth = -140"), Err(String::from("[day3]: 'width' must be a non-negative integer, got -140")));
+    assert_eq!(check("[day3]\nwdith = 140"), Err(String::from("[day3]: unknown parameter 'wdith'")));
+    // Days without a solution may be configured ahead of time
+    assert_eq!(check("[2022.day5]\nseeds = 1"), Ok(()));
+}
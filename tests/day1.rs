@@ -0,0 +1,48 @@
+use advent_of_code::config::Params;
+use advent_of_code::solution::{catch_panic, run, run_part, Answer, RunOptions};
+use advent_of_code::year2023::day1::Day1;
+
+
+const PART1_EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+const PART2_EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+#[test]
+fn examples() {
+    let part1 = run_part::<Day1>(PART1_EXAMPLE.as_bytes(), &Params::default(), 1, RunOptions::default());
+    assert_eq!(part1.answer, Answer::from(142));
+
+    let part2 = run_part::<Day1>(PART2_EXAMPLE.as_bytes(), &Params::default(), 2, RunOptions::default());
+    assert_eq!(part2.answer, Answer::from(281));
+}
+
+#[test]
+fn both_parts_from_one_parse() {
+    let run = run::<Day1>(PART1_EXAMPLE.as_bytes(), &Params::default(), RunOptions::default());
+    assert_eq!((run.part1, run.part2), (Answer::from(142), Answer::from(142)));
+}
+
+#[test]
+fn part2_example_has_lines_without_plain_digits() {
+    let part1 = catch_panic(|| run_part::<Day1>(PART2_EXAMPLE.as_bytes(), &Params::default(), 1, RunOptions::default()));
+    assert_eq!(part1.unwrap_err(), "line 2 has no digits");
+}
+
+#[test]
+#[should_panic(expected = "line 3 has no digits, spelled out or not")]
+fn line_without_any_digits() {
+    run_part::<Day1>(b"1abc2\ntwo\nabc\n", &Params::default(), 2, RunOptions::default());
+}
@@ -0,0 +1,26 @@
+use advent_of_code::config::Params;
+use advent_of_code::year2023::day10::Day10;
+use advent_of_code::solution::{run, run_strategies, Answer, RunOptions};
+
+
+/// A stray pipe leads into S from the west, next to the two that belong to the loop
+const STRAY_PIPE_INTO_START: &str = "\
+..........
+------S-7.
+......|.|.
+......L-J.
+..........
+";
+
+#[test]
+fn stray_pipe_into_start() {
+    let input = STRAY_PIPE_INTO_START.as_bytes();
+    let run = run::<Day10>(input, &Params::default(), RunOptions::default());
+    assert_eq!(run.part1, Answer::from(4));
+    assert_eq!(run.part2, Answer::from(1));
+
+    for strategy in run_strategies::<Day10>(input, &Params::default(), RunOptions::default()) {
+        let expected = if strategy.part == 1 { 4 } else { 1 };
+        assert_eq!(strategy.answer, Answer::from(expected), "part {} {}", strategy.part, strategy.name);
+    }
+}
@@ -0,0 +1,48 @@
+use advent_of_code::config::{Params, Value};
+use advent_of_code::solution::{run, Answer, RunOptions};
+use advent_of_code::year2023::day3::Day3;
+
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+fn run_sized(input: &str, width: i64, height: i64) -> (Answer, Answer) {
+    let mut params = Params::default();
+    params.set("width", Value::Int(width));
+    params.set("height", Value::Int(height));
+    let run = run::<Day3>(input.as_bytes(), &params, RunOptions::default());
+    (run.part1, run.part2)
+}
+
+#[test]
+fn example() {
+    assert_eq!(run_sized(EXAMPLE, 10, 10), (Answer::from(4361), Answer::from(467835)));
+}
+
+#[test]
+#[should_panic(expected = "the schematic is not 12 lines tall")]
+fn fewer_rows_than_height() {
+    run_sized(EXAMPLE, 10, 12);
+}
+
+#[test]
+#[should_panic(expected = "the schematic is not 8 lines tall")]
+fn more_rows_than_height() {
+    run_sized(EXAMPLE, 10, 8);
+}
+
+#[test]
+#[should_panic(expected = "line 3 of the schematic is not 10 wide")]
+fn ragged_row() {
+    run_sized(&EXAMPLE.replacen("..35..633.", "..35..633", 1), 10, 10);
+}
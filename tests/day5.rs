@@ -0,0 +1,78 @@
+use advent_of_code::config::Params;
+use advent_of_code::rng::Rng;
+use advent_of_code::solution::{run, Answer, RunOptions, Solution};
+use advent_of_code::year2023::day5::Day5;
+
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+fn check_assumptions(input: &str) -> Vec<Result<(), String>> {
+    Day5::assumptions().iter().map(|a| (a.check)(input.as_bytes(), &Params::default())).collect()
+}
+
+#[test]
+fn example() {
+    let run = run::<Day5>(EXAMPLE.as_bytes(), &Params::default(), RunOptions::default());
+    assert_eq!((run.part1, run.part2), (Answer::from(35), Answer::from(46)));
+    assert_eq!(check_assumptions(EXAMPLE), [Ok(()), Ok(())]);
+}
+
+#[test]
+fn overlapping_ranges() {
+    let input = EXAMPLE.replace("52 50 48", "52 50 49");
+    assert_eq!(check_assumptions(&input)[0], Err(String::from("map 1 has source ranges 50..99 and 98..100 overlapping")));
+}
+
+#[test]
+fn range_past_the_largest_number() {
+    let input = EXAMPLE.replace("50 98 2", &format!("50 {} 2", usize::MAX));
+    assert_eq!(check_assumptions(&input)[0], Err(format!(
+        "map 1 has a source range 2 long from {}, which ends past {}", usize::MAX, usize::MAX)));
+}
+
+#[test]
+fn anonymised_input_keeps_both_answers() {
+    for seed in 0..20 {
+        let anonymised = Day5::anonymise(EXAMPLE.as_bytes(), &mut Rng::new(seed)).unwrap();
+        let text = String::from_utf8(anonymised).unwrap();
+        assert_ne!(text, EXAMPLE);
+
+        let run = run::<Day5>(text.as_bytes(), &Params::default(), RunOptions::default());
+        assert_eq!((run.part1, run.part2), (Answer::from(35), Answer::from(46)), "seed {}:\n{}", seed, text);
+        assert_eq!(check_assumptions(&text), [Ok(()), Ok(())]);
+    }
+}
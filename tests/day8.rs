@@ -0,0 +1,111 @@
+use std::env;
+use std::fs;
+use std::process::{self, Command};
+use advent_of_code::config::Params;
+use advent_of_code::solution::{run, run_part, Answer, RunOptions, Solution};
+use advent_of_code::year2023::day8::Day8;
+
+
+const PART1_EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const PART1_REPEATED_EXAMPLE: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const PART2_EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+/// The ghost's `..Z` comes round every 3 steps, while its (node, instruction) states
+/// only repeat every 6
+const PERIOD_NOT_A_MULTIPLE_OF_INSTRUCTIONS: &str = "\
+LR
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+11Z = (11B, 11B)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+fn part(input: &str, part: u8) -> Answer {
+    run_part::<Day8>(input.as_bytes(), &Params::default(), part, RunOptions::default()).answer
+}
+
+fn check_assumptions(input: &str) -> Vec<Result<(), String>> {
+    Day8::assumptions().iter().map(|a| (a.check)(input.as_bytes(), &Params::default())).collect()
+}
+
+#[test]
+fn examples() {
+    assert_eq!(part(PART1_EXAMPLE, 1), Answer::from(2));
+    assert_eq!(part(PART1_REPEATED_EXAMPLE, 1), Answer::from(6));
+
+    let run = run::<Day8>(PART2_EXAMPLE.as_bytes(), &Params::default(), RunOptions::default());
+    assert_eq!(run.part2, Answer::from(6));
+    assert_eq!(check_assumptions(PART2_EXAMPLE), [Ok(()), Ok(())]);
+}
+
+#[test]
+fn ghost_period_is_not_its_state_cycle() {
+    assert_eq!(part(PERIOD_NOT_A_MULTIPLE_OF_INSTRUCTIONS, 2), Answer::from(3));
+    assert_eq!(check_assumptions(PERIOD_NOT_A_MULTIPLE_OF_INSTRUCTIONS), [Ok(()), Ok(())]);
+}
+
+#[test]
+fn ghost_meeting_two_ends_breaks_the_assumption() {
+    let input = "\
+L
+
+11A = (11Z, 11Z)
+11Z = (22Z, 22Z)
+22Z = (11Z, 11Z)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+    let checks = check_assumptions(input);
+    assert_eq!(checks[1], Err(String::from(
+        "ghost from Node { \"11A\" } reaches Node { \"11Z\" } after 1 steps but then Node { \"22Z\" } after 2")));
+}
+
+#[test]
+fn binary_gives_up_after_timeout() {
+    let path = env::temp_dir().join(format!("aoc-day8-{}.txt", process::id()));
+    fs::write(&path, "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_day8"))
+        .arg(&path)
+        .args(["--part", "1", "--timeout", "0.2"])
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.starts_with("Part 1 result: timed out ("), "{}", stdout);
+}
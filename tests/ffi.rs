@@ -0,0 +1,86 @@
+#![cfg(feature = "c-api")]
+
+use std::env;
+use std::ffi::CStr;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::thread;
+use advent_of_code::ffi::{self, Status};
+
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn header_is_current() {
+    let path = root().join("include").join("aoc.h");
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, ffi::header()).unwrap();
+        return;
+    }
+
+    let committed = fs::read_to_string(&path).unwrap_or_default();
+    assert!(committed == ffi::header(),
+        "{} is out of date, run with UPDATE_SNAPSHOTS=1 to regenerate it", path.display());
+}
+
+/// `aoc_solve` into a buffer of its own, as (status, text)
+fn solve(day: u8, part: u8, input: &[u8]) -> (i32, String) {
+    let mut out = [0u8; 256];
+    let status = unsafe {
+        ffi::aoc_solve(day, part, input.as_ptr(), input.len(), out.as_mut_ptr().cast(), out.len())
+    };
+    (status, CStr::from_bytes_until_nul(&out).unwrap().to_string_lossy().into_owned())
+}
+
+#[test]
+fn panics_are_caught_on_many_threads() {
+    let threads: Vec<_> = (0..8).map(|i| thread::spawn(move || {
+        for _ in 0..20 {
+            if i % 2 == 0 {
+                assert_eq!(solve(1, 1, b"1abc2\npqr3stu8vwx\n"), (Status::Ok as i32, String::from("50")));
+            } else {
+                let (status, message) = solve(3, 1, b"1.\n");
+                assert_eq!(status, Status::Panicked as i32);
+                assert!(message.contains("not 140 wide"), "{}", message);
+            }
+        }
+    })).collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+#[test]
+fn c_test_solves_every_day() {
+    // The tests link the rlib, so build the shared library on the side in a target
+    // directory of its own, which the outer cargo does not hold a lock on
+    let target = root().join("target").join("c-api");
+    let built = Command::new(env!("CARGO"))
+        .current_dir(root())
+        .args(["rustc", "--lib", "--release", "--features", "c-api", "--crate-type", "cdylib", "--target-dir"])
+        .arg(&target)
+        .status()
+        .unwrap();
+    assert!(built.success(), "building the shared library failed");
+
+    let lib_dir = target.join("release");
+    let exe = target.join("solve");
+    let compiled = Command::new(env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg(root().join("tests").join("c").join("solve.c"))
+        .arg("-I").arg(root().join("include"))
+        .arg("-L").arg(&lib_dir)
+        .arg("-ladvent_of_code")
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-o").arg(&exe)
+        .status()
+        .unwrap();
+    assert!(compiled.success(), "compiling tests/c/solve.c failed");
+
+    let output = Command::new(&exe).arg(root()).output().unwrap();
+    assert!(output.status.success(), "{}{}",
+        String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+}
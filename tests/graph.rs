@@ -0,0 +1,55 @@
+use advent_of_code::graph::{self, Cycle, Graph, Interner};
+
+
+fn graph(edges: &[(usize, usize, u32)]) -> Graph<u32> {
+    let mut graph = Graph::default();
+    for &(from, to, weight) in edges {
+        graph.add_edge(from, to, weight);
+    }
+    graph
+}
+
+#[test]
+fn interns_in_first_seen_order() {
+    let mut names = Interner::new();
+    assert_eq!(names.intern("b"), 0);
+    assert_eq!(names.intern("a"), 1);
+    assert_eq!(names.intern("b"), 0);
+    assert_eq!(names.get(&"a"), Some(1));
+    assert_eq!(names.get(&"c"), None);
+    assert_eq!(*names.key(1), "a");
+    assert_eq!(names.len(), 2);
+}
+
+#[test]
+fn searches() {
+    let g = graph(&[(0, 1, 4), (0, 2, 1), (2, 1, 1), (1, 3, 1), (4, 0, 1)]);
+
+    assert_eq!(g.bfs(0), [Some(0), Some(1), Some(1), Some(2), None]);
+    assert_eq!(g.dfs(0), [0, 1, 3, 2]);
+    assert_eq!(g.dijkstra(0), [Some(0), Some(2), Some(1), Some(3), None]);
+}
+
+#[test]
+fn strongly_connected_components() {
+    let g = graph(&[(0, 1, 0), (1, 2, 0), (2, 0, 0), (2, 3, 0), (3, 4, 0), (4, 3, 0), (5, 4, 0)]);
+
+    let mut components = g.strongly_connected();
+    for c in &mut components {
+        c.sort();
+    }
+    // Components come before the ones that lead into them
+    assert_eq!(components, [vec![3, 4], vec![0, 1, 2], vec![5]]);
+}
+
+#[test]
+fn detects_cycles() {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2
+    let step = |x: u32| if x == 5 { 2 } else { x + 1 };
+    let expected = Cycle { start: 2, len: 4 };
+
+    assert_eq!(graph::floyd(0, step), expected);
+    assert_eq!(graph::brent(0, step), expected);
+    assert_eq!(graph::brent(3, step), Cycle { start: 0, len: 4 });
+    assert_eq!(graph::floyd(7, |x| x), Cycle { start: 0, len: 1 });
+}
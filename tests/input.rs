@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+use std::env;
+use std::fs;
+use std::process;
+use advent_of_code::input::{self, load_with, normalise, parse_int, parse_uint, LoadOptions};
+
+
+const KEEP_WHITESPACE: LoadOptions = LoadOptions { trim_trailing_whitespace: false };
+
+fn normalised(bytes: &[u8], options: LoadOptions) -> String {
+    String::from_utf8(normalise(bytes, options).into_owned()).unwrap()
+}
+
+#[test]
+fn normalise_line_endings() {
+    let options = LoadOptions::default();
+    assert_eq!(normalised(b"\xef\xbb\xbfa\nb\n", options), "a\nb\n");
+    assert_eq!(normalised(b"a\r\nb\r\n", options), "a\nb\n");
+    assert_eq!(normalised(b"a\rb\r", options), "a\nb\n");
+    assert_eq!(normalised(b"a\r\n\rb\n", options), "a\n\nb\n");
+    assert_eq!(normalised(b"a\nb", options), "a\nb\n");
+    assert_eq!(normalised(b"", options), "");
+}
+
+#[test]
+fn normalise_trailing_blank_lines() {
+    let options = LoadOptions::default();
+    assert_eq!(normalised(b"a\nb\n\n", options), "a\nb\n");
+    assert_eq!(normalised(b"a\n\nb\n\n\n\n", options), "a\n\nb\n");
+    assert_eq!(normalised(b"a\r\n\r\n", options), "a\n");
+    assert_eq!(normalised(b"a\n \n\t\n", options), "a\n");
+    assert_eq!(normalised(b"a\n \n", KEEP_WHITESPACE), "a\n \n");
+    assert_eq!(normalised(b"\n\n", options), "");
+}
+
+#[test]
+fn normalise_trailing_whitespace() {
+    assert_eq!(normalised(b"a  \nb\t\n", LoadOptions::default()), "a\nb\n");
+    assert_eq!(normalised(b"a  \nb\t\n", KEEP_WHITESPACE), "a  \nb\t\n");
+    assert_eq!(normalised(b"  a\n", LoadOptions::default()), "  a\n");
+}
+
+#[test]
+fn clean_input_is_borrowed() {
+    assert!(matches!(normalise(b"a\nb\n", LoadOptions::default()), Cow::Borrowed(_)));
+    assert!(matches!(normalise(b"\xef\xbb\xbfa\n", LoadOptions::default()), Cow::Borrowed(b"a\n")));
+    assert!(matches!(normalise(b"a \n", LoadOptions::default()), Cow::Owned(_)));
+    assert!(matches!(normalise(b"a\n\n", LoadOptions::default()), Cow::Owned(_)));
+    assert!(matches!(normalise(b"a \n", KEEP_WHITESPACE), Cow::Borrowed(_)));
+}
+
+#[test]
+fn load_with_normalises_files() {
+    let path = env::temp_dir().join(format!("aoc-input-{}.txt", process::id()));
+    fs::write(&path, b"\xef\xbb\xbf1 2 \r\n3\r4").unwrap();
+    let loaded = load_with(&path, LoadOptions::default());
+    let kept = load_with(&path, KEEP_WHITESPACE);
+    fs::remove_file(&path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded, b"1 2\n3\n4\n");
+    assert_eq!(input::lines(&loaded).collect::<Vec<_>>(), [&b"1 2"[..], b"3", b"4"]);
+    assert_eq!(kept.unwrap(), b"1 2 \n3\n4\n");
+}
+
+#[test]
+fn parse_uint_bounds() {
+    assert_eq!(parse_uint::<u32>(b"0"), Some(0));
+    assert_eq!(parse_uint::<u32>(b"007"), Some(7));
+    assert_eq!(parse_uint::<u8>(b"255"), Some(255));
+    assert_eq!(parse_uint::<u8>(b"256"), None);
+    assert_eq!(parse_uint::<u64>(b"18446744073709551615"), Some(u64::MAX));
+    assert_eq!(parse_uint::<u64>(b"18446744073709551616"), None);
+}
+
+#[test]
+fn parse_uint_rejects_signs_and_stray_bytes() {
+    assert_eq!(parse_uint::<u32>(b""), None);
+    assert_eq!(parse_uint::<u32>(b"-1"), None);
+    assert_eq!(parse_uint::<u32>(b"+1"), None);
+    assert_eq!(parse_uint::<u32>(b" 1"), None);
+    assert_eq!(parse_uint::<u32>(b"1_000"), None);
+}
+
+#[test]
+fn parse_int_signs() {
+    assert_eq!(parse_int::<i32>(b"42"), Some(42));
+    assert_eq!(parse_int::<i32>(b"+42"), Some(42));
+    assert_eq!(parse_int::<i32>(b"-42"), Some(-42));
+    assert_eq!(parse_int::<i32>(b"-0"), Some(0));
+    assert_eq!(parse_int::<i32>(b""), None);
+    assert_eq!(parse_int::<i32>(b"-"), None);
+    assert_eq!(parse_int::<i32>(b"+"), None);
+    assert_eq!(parse_int::<i32>(b"--1"), None);
+    assert_eq!(parse_int::<i32>(b"+-1"), None);
+}
+
+#[test]
+fn parse_int_overflow() {
+    assert_eq!(parse_int::<i64>(b"9223372036854775807"), Some(i64::MAX));
+    assert_eq!(parse_int::<i64>(b"-9223372036854775808"), Some(i64::MIN));
+    assert_eq!(parse_int::<i64>(b"9223372036854775808"), None);
+    assert_eq!(parse_int::<i64>(b"-9223372036854775809"), None);
+    assert_eq!(parse_int::<i8>(b"-128"), Some(-128));
+    assert_eq!(parse_int::<i8>(b"128"), None);
+    assert_eq!(parse_int::<u8>(b"-1"), None);
+}
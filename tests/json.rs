@@ -0,0 +1,28 @@
+use advent_of_code::json::Json;
+
+
+#[test]
+fn compact_and_indented() {
+    let json = Json::object([
+        ("name", Json::from("a \"quoted\"\nline\u{1}")),
+        ("point", Json::from((3, -4))),
+        ("loop", Json::array([(0, 0), (1, 0)])),
+        ("empty", Json::array(Vec::<i32>::new())),
+        ("missing", Json::from(None::<u8>)),
+        ("ratio", Json::from(f64::NAN)),
+    ]);
+
+    assert_eq!(json.to_string(),
+        r#"{"name":"a \"quoted\"\nline\u0001","point":[3,-4],"loop":[[0,0],[1,0]],"empty":[],"missing":null,"ratio":null}"#);
+    assert_eq!(format!("{:#}", json), r#"{
+  "name": "a \"quoted\"\nline\u0001",
+  "point": [3, -4],
+  "loop": [
+    [0, 0],
+    [1, 0]
+  ],
+  "empty": [],
+  "missing": null,
+  "ratio": null
+}"#);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use advent_of_code::bigint::BigInt;
+use advent_of_code::config::{Params, Value};
+use advent_of_code::solution::{run, Answer, RunOptions, Solution};
+use advent_of_code::year2023::day3::Day3;
+use advent_of_code::year2023::day7::Day7;
+use advent_of_code::year2023::day10::Day10;
+use common::assert_snapshot;
+
+
+fn render<S: Solution>(input: &str, params: &Params) -> String {
+    run::<S>(input.as_bytes(), params, RunOptions::default()).render.unwrap()
+}
+
+#[test]
+fn day3_schematic() {
+    let input = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+    let mut params = Params::default();
+    params.set("width", Value::Int(10));
+    params.set("height", Value::Int(10));
+
+    assert_snapshot("day3_schematic", &render::<Day3>(input, &params));
+}
+
+#[test]
+fn day7_ranked_hands() {
+    let input = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+    assert_snapshot("day7_ranked_hands", &render::<Day7>(input, &Params::default()));
+}
+
+#[test]
+fn day10_pipe_maze() {
+    let input = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+    assert_snapshot("day10_pipe_maze", &render::<Day10>(input, &Params::default()));
+}
+
+#[test]
+fn answers() {
+    let big: BigInt = "-340282366920938463463374607431768211456000".parse().unwrap();
+    let answers = [
+        Answer::from(42u32),
+        Answer::from(-7i64),
+        Answer::from(big),
+        Answer::Unsolved,
+    ];
+    let rendered: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+
+    assert_snapshot("answers", &(rendered.join("\n") + "\n"));
+}
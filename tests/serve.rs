@@ -0,0 +1,85 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.as_mut().unwrap()).read_line(&mut line).unwrap();
+        let addr = line.trim().strip_prefix("listening on http://").unwrap().to_string();
+
+        Server { child, addr }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method, path, body.len(), body).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const DAY9_EXAMPLE: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+#[test]
+fn solves_posted_input() {
+    let server = Server::start();
+
+    let part1 = server.request("POST", "/day/9/part/1", DAY9_EXAMPLE);
+    assert!(part1.starts_with("HTTP/1.1 200 OK"), "{}", part1);
+    assert!(part1.contains("\"day\":9,\"part\":1,\"answer\":\"114\""), "{}", part1);
+    assert!(part1.contains("\"solve_ms\":"), "{}", part1);
+
+    let part2 = server.request("POST", "/day/9/part/2", DAY9_EXAMPLE);
+    assert!(part2.contains("\"answer\":\"2\""), "{}", part2);
+
+    let by_year = server.request("POST", "/2023/day/9/part/1", DAY9_EXAMPLE);
+    assert!(by_year.contains("\"year\":2023,\"day\":9,\"part\":1,\"answer\":\"114\""), "{}", by_year);
+}
+
+#[test]
+fn reports_errors() {
+    let server = Server::start();
+
+    assert!(server.request("POST", "/day/99/part/1", "").starts_with("HTTP/1.1 404"));
+    assert!(server.request("POST", "/1999/day/9/part/1", "").starts_with("HTTP/1.1 404"));
+    assert!(server.request("POST", "/day/9/part/3", "").starts_with("HTTP/1.1 404"));
+    assert!(server.request("GET", "/day/9/part/1", "").starts_with("HTTP/1.1 405"));
+    assert!(server.request("POST", "/day/4/part/1", "garbage").starts_with("HTTP/1.1 500"));
+}
+
+#[test]
+fn refuses_oversized_input() {
+    let server = Server::start();
+
+    let mut stream = TcpStream::connect(&server.addr).unwrap();
+    write!(stream, "POST /day/9/part/1 HTTP/1.1\r\nContent-Length: 99999999999999999\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 413"), "{}", response);
+
+    // Still up for the next client
+    assert!(server.request("POST", "/day/9/part/1", DAY9_EXAMPLE).starts_with("HTTP/1.1 200 OK"));
+}